@@ -8,7 +8,7 @@ pub async fn validate(request: ServiceRequest, bearer: BearerAuth) -> Result<Ser
     let token = bearer.token();
     let facebook_service = FacebookService::new();
 
-    let facebook_profile = facebook_service.get_facebook_profile(&token).await;
+    let facebook_profile = facebook_service.get_facebook_profile(token).await;
     match facebook_profile {
         Ok(_) => Ok(request),
         Err(_) => {
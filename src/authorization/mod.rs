@@ -1 +1,2 @@
-pub mod bearer_auth;
\ No newline at end of file
+pub mod bearer_auth;
+pub mod request_user;
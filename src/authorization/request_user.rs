@@ -0,0 +1,23 @@
+use actix_web::HttpRequest;
+
+use crate::model::user::User;
+use crate::repository::mongo_db_user_repository::MongoDBUserRepository;
+use crate::service::facebook_service::FacebookService;
+use crate::service::user_service::UserService;
+
+pub async fn get_request_user(
+    req: &HttpRequest,
+    facebook_service: &FacebookService,
+    user_service: &UserService<MongoDBUserRepository>,
+) -> Option<User> {
+    let authorization_header = get_authorization_header(req)?;
+    let authorization_bearer = authorization_header.replace("Bearer ", "");
+
+    let facebook_profile = facebook_service.get_facebook_profile(authorization_bearer.as_str()).await.ok()?;
+    let user = User::from_facebook_profile(facebook_profile);
+    Some(user_service.get_user(&user.id).await.unwrap_or(user))
+}
+
+fn get_authorization_header(req: &HttpRequest) -> Option<&str> {
+    req.headers().get("Authorization")?.to_str().ok()
+}
@@ -0,0 +1,35 @@
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+
+use crate::authorization::request_user::get_request_user;
+use crate::model::leaderboard::{LeaderboardKind, LeaderboardQuery};
+use crate::repository::leaderboard_repository::LeaderboardRepository;
+use crate::repository::mongo_db_user_repository::MongoDBUserRepository;
+use crate::service::facebook_service::FacebookService;
+use crate::service::leaderboard_service::LeaderboardService;
+use crate::service::user_service::UserService;
+
+pub async fn get_leaderboard<T: LeaderboardRepository>(
+    req: HttpRequest,
+    kind: web::Path<LeaderboardKind>,
+    query: web::Query<LeaderboardQuery>,
+    leaderboard_service: web::Data<LeaderboardService<T>>,
+    facebook_service: web::Data<FacebookService>,
+    user_service: web::Data<UserService<MongoDBUserRepository>>,
+) -> impl Responder {
+    let user = get_request_user(&req, &facebook_service, &user_service).await;
+    let user_id = user.as_ref().map(|user| user.id.as_str());
+    let leaderboard = leaderboard_service.get_leaderboard(kind.into_inner(), &query, user_id).await;
+    HttpResponse::Ok().json(leaderboard)
+}
+
+pub async fn get_season_archive<T: LeaderboardRepository>(
+    path: web::Path<(LeaderboardKind, u32)>,
+    leaderboard_service: web::Data<LeaderboardService<T>>,
+) -> impl Responder {
+    let (kind, season) = path.into_inner();
+    if let Some(archive) = leaderboard_service.get_season_archive(kind, season).await {
+        HttpResponse::Ok().json(archive)
+    } else {
+        HttpResponse::NotFound().body("Season not found")
+    }
+}
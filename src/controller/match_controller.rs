@@ -0,0 +1,27 @@
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+
+use crate::authorization::request_user::get_request_user;
+use crate::model::match_record::MatchRecord;
+use crate::repository::mongo_db_user_repository::MongoDBUserRepository;
+use crate::repository::repository::Repository;
+use crate::service::facebook_service::FacebookService;
+use crate::service::match_service::MatchService;
+use crate::service::user_service::UserService;
+
+pub async fn get_replay<T: Repository<MatchRecord>>(
+    req: HttpRequest,
+    id: web::Path<String>,
+    match_service: web::Data<MatchService<T>>,
+    facebook_service: web::Data<FacebookService>,
+    user_service: web::Data<UserService<MongoDBUserRepository>>,
+) -> impl Responder {
+    let user = match get_request_user(&req, &facebook_service, &user_service).await {
+        Some(user) => user,
+        None => return HttpResponse::Unauthorized().finish(),
+    };
+    if let Some(match_record) = match_service.get_match(&id.into_inner()).await {
+        HttpResponse::Ok().json(match_record.visible_to(&user.id))
+    } else {
+        HttpResponse::NotFound().body("Match not found")
+    }
+}
@@ -1,2 +1,5 @@
 pub mod user_controller;
-pub mod facebook_controller;
\ No newline at end of file
+pub mod facebook_controller;
+pub mod match_controller;
+pub mod tournament_controller;
+pub mod leaderboard_controller;
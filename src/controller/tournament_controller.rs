@@ -0,0 +1,110 @@
+use actix::Addr;
+use actix_web::{HttpRequest, HttpResponse, Responder, web};
+
+use crate::authorization::request_user::get_request_user;
+use crate::model::tournament::{CreateTournamentRequest, Tournament, TournamentError};
+use crate::model::tournament_manager_messages::{CreateTournament, GetTournament, RegisterForTournament, StartTournament};
+use crate::repository::mongo_db_user_repository::MongoDBUserRepository;
+use crate::repository::repository::Repository;
+use crate::service::facebook_service::FacebookService;
+use crate::service::tournament_service::TournamentService;
+use crate::service::user_service::UserService;
+use crate::tournament::tournament_manager::TournamentManager;
+
+pub async fn create_tournament(
+    req: HttpRequest,
+    request: web::Json<CreateTournamentRequest>,
+    tournament_manager: web::Data<Addr<TournamentManager>>,
+    facebook_service: web::Data<FacebookService>,
+    user_service: web::Data<UserService<MongoDBUserRepository>>,
+) -> impl Responder {
+    let user = match get_request_user(&req, &facebook_service, &user_service).await {
+        Some(user) => user,
+        None => return HttpResponse::Unauthorized().finish(),
+    };
+    let result = tournament_manager.send(CreateTournament {
+        user,
+        request: request.into_inner(),
+    }).await;
+    match result {
+        Ok(Ok(tournament)) => HttpResponse::Created().json(tournament),
+        Ok(Err(error)) => error_response(error),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
+
+pub async fn get_tournament<T: Repository<Tournament>>(
+    id: web::Path<String>,
+    tournament_manager: web::Data<Addr<TournamentManager>>,
+    tournament_service: web::Data<TournamentService<T>>,
+) -> impl Responder {
+    let tournament_id = id.into_inner();
+    let active_tournament = tournament_manager.send(GetTournament {
+        tournament_id: tournament_id.clone(),
+    }).await.ok().flatten();
+    let tournament = match active_tournament {
+        Some(tournament) => Some(tournament),
+        None => tournament_service.get_tournament(&tournament_id).await,
+    };
+    match tournament {
+        Some(tournament) => HttpResponse::Ok().json(tournament),
+        None => error_response(TournamentError::NotFound),
+    }
+}
+
+pub async fn register(
+    req: HttpRequest,
+    id: web::Path<String>,
+    tournament_manager: web::Data<Addr<TournamentManager>>,
+    facebook_service: web::Data<FacebookService>,
+    user_service: web::Data<UserService<MongoDBUserRepository>>,
+) -> impl Responder {
+    let user = match get_request_user(&req, &facebook_service, &user_service).await {
+        Some(user) => user,
+        None => return HttpResponse::Unauthorized().finish(),
+    };
+    let result = tournament_manager.send(RegisterForTournament {
+        user,
+        tournament_id: id.into_inner(),
+    }).await;
+    match result {
+        Ok(Ok(tournament)) => HttpResponse::Ok().json(tournament),
+        Ok(Err(error)) => error_response(error),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
+
+pub async fn start_tournament(
+    req: HttpRequest,
+    id: web::Path<String>,
+    tournament_manager: web::Data<Addr<TournamentManager>>,
+    facebook_service: web::Data<FacebookService>,
+    user_service: web::Data<UserService<MongoDBUserRepository>>,
+) -> impl Responder {
+    let user = match get_request_user(&req, &facebook_service, &user_service).await {
+        Some(user) => user,
+        None => return HttpResponse::Unauthorized().finish(),
+    };
+    let result = tournament_manager.send(StartTournament {
+        user,
+        tournament_id: id.into_inner(),
+    }).await;
+    match result {
+        Ok(Ok(tournament)) => HttpResponse::Ok().json(tournament),
+        Ok(Err(error)) => error_response(error),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    }
+}
+
+fn error_response(error: TournamentError) -> HttpResponse {
+    let mut response = match error {
+        TournamentError::NotFound => HttpResponse::NotFound(),
+        TournamentError::Forbidden => HttpResponse::Forbidden(),
+        TournamentError::InvalidSettings => HttpResponse::BadRequest(),
+        TournamentError::RegistrationClosed
+        | TournamentError::TournamentFull
+        | TournamentError::AlreadyRegistered
+        | TournamentError::NotEnoughPlayers => HttpResponse::Conflict(),
+    };
+    response.body(error.message())
+}
@@ -1,7 +1,12 @@
 use actix_web::{HttpResponse, Responder, web};
 
+use crate::model::player_stats::PlayerStats;
 use crate::model::user::User;
+use crate::repository::achievement_repository::AchievementRepository;
+use crate::repository::player_stats_repository::PlayerStatsRepository;
 use crate::repository::repository::Repository;
+use crate::service::achievement_service::AchievementService;
+use crate::service::player_stats_service::PlayerStatsService;
 use crate::service::user_service::UserService;
 
 pub async fn get_user<T: Repository<User>>(
@@ -22,3 +27,21 @@ pub async fn create_user<T: Repository<User>>(
     user_service.create_user(user.into_inner()).await;
     HttpResponse::Created().body("User created successfully")
 }
+
+pub async fn get_user_stats<T: PlayerStatsRepository>(
+    id: web::Path<String>,
+    player_stats_service: web::Data<PlayerStatsService<T>>,
+) -> impl Responder {
+    let user_id = id.into_inner();
+    let player_stats = player_stats_service.get_player_stats(&user_id).await
+        .unwrap_or_else(|| PlayerStats::new(user_id));
+    HttpResponse::Ok().json(player_stats)
+}
+
+pub async fn get_user_achievements<T: AchievementRepository>(
+    id: web::Path<String>,
+    achievement_service: web::Data<AchievementService<T>>,
+) -> impl Responder {
+    let badges = achievement_service.get_badges(&id.into_inner()).await;
+    HttpResponse::Ok().json(badges)
+}
@@ -0,0 +1,91 @@
+use std::time::{Duration, Instant};
+
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold,
+            cooldown,
+            consecutive_failures: 0,
+            opened_at: None,
+        }
+    }
+
+    pub fn allow_request(&mut self) -> bool {
+        match self.opened_at {
+            None => true,
+            Some(opened_at) if opened_at.elapsed() >= self.cooldown => {
+                // Half-open: let this request probe the backend and keep others out until it reports back.
+                self.opened_at = Some(Instant::now());
+                true
+            }
+            Some(_) => false,
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+    }
+
+    pub fn record_failure(&mut self) {
+        self.consecutive_failures += 1;
+        if self.consecutive_failures >= self.failure_threshold {
+            self.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let mut circuit_breaker = CircuitBreaker::new(3, Duration::from_secs(60));
+        circuit_breaker.record_failure();
+        circuit_breaker.record_failure();
+        assert!(circuit_breaker.allow_request());
+        circuit_breaker.record_failure();
+        assert!(!circuit_breaker.allow_request());
+    }
+
+    #[test]
+    fn success_resets_the_failure_count() {
+        let mut circuit_breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+        circuit_breaker.record_failure();
+        circuit_breaker.record_success();
+        circuit_breaker.record_failure();
+        assert!(circuit_breaker.allow_request());
+    }
+
+    #[test]
+    fn half_open_lets_one_probe_through_after_the_cooldown() {
+        let mut circuit_breaker = CircuitBreaker::new(1, Duration::ZERO);
+        circuit_breaker.record_failure();
+        assert!(circuit_breaker.allow_request());
+
+        circuit_breaker.cooldown = Duration::from_secs(60);
+        assert!(!circuit_breaker.allow_request());
+        circuit_breaker.record_failure();
+        assert!(!circuit_breaker.allow_request());
+    }
+
+    #[test]
+    fn closes_when_the_probe_succeeds() {
+        let mut circuit_breaker = CircuitBreaker::new(1, Duration::ZERO);
+        circuit_breaker.record_failure();
+        assert!(circuit_breaker.allow_request());
+        circuit_breaker.record_success();
+
+        circuit_breaker.cooldown = Duration::from_secs(60);
+        assert!(circuit_breaker.allow_request());
+        assert!(circuit_breaker.allow_request());
+    }
+}
@@ -0,0 +1,6 @@
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait Dictionary: Send + Sync {
+    async fn word_exists(&self, word: &str) -> Result<bool, Box<dyn std::error::Error>>;
+}
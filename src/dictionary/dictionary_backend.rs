@@ -0,0 +1,14 @@
+pub enum DictionaryBackend {
+    Local,
+    Http,
+}
+
+impl DictionaryBackend {
+    pub fn from_config(value: Option<&String>) -> Result<Self, Box<dyn std::error::Error>> {
+        match value.map(|value| value.to_ascii_lowercase()).as_deref() {
+            Some("local") => Ok(DictionaryBackend::Local),
+            Some("http") | None => Ok(DictionaryBackend::Http),
+            Some(other) => Err(format!("Unknown dictionary_backend: {}", other).into()),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::dictionary::dictionary::Dictionary;
+use crate::dictionary::dictionary_backend::DictionaryBackend;
+use crate::dictionary::http_dictionary::HttpDictionary;
+use crate::dictionary::local_dictionary::LocalDictionary;
+use crate::language::language_registry::LanguageDefinition;
+use crate::service::env_service::EnvService;
+
+pub struct DictionaryFactory;
+impl DictionaryFactory {
+    pub fn create_dictionary(
+        backend: DictionaryBackend,
+        language_definition: &LanguageDefinition,
+        env_service: &EnvService,
+    ) -> Result<Box<dyn Dictionary>, Box<dyn std::error::Error>> {
+        match backend {
+            DictionaryBackend::Local => {
+                let key = language_definition.config_key("dictionary_path");
+                let dictionary_path = match env_service.env_data.get(&key) {
+                    Some(value) => value,
+                    None => { return Err(format!("No {} in .env", key).into()); }
+                };
+                Ok(Box::new(LocalDictionary::load(dictionary_path)?))
+            }
+            DictionaryBackend::Http => {
+                let key = language_definition.config_key("dictionary_uri");
+                let dictionary_uri = match env_service.env_data.get(&key) {
+                    Some(value) => value,
+                    None => { return Err(format!("No {} in .env", key).into()); }
+                };
+                Ok(Box::new(HttpDictionary::new(dictionary_uri.clone())?))
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::dictionary::circuit_breaker::CircuitBreaker;
+use crate::dictionary::dictionary::Dictionary;
+use crate::dictionary::lru_cache::LruCache;
+use crate::util::constants::{DICTIONARY_CACHE_CAPACITY, DICTIONARY_CACHE_TTL_SECONDS, DICTIONARY_CIRCUIT_COOLDOWN_SECONDS, DICTIONARY_CIRCUIT_FAILURE_THRESHOLD, DICTIONARY_MAX_RETRIES, DICTIONARY_RETRY_BACKOFF_MILLISECONDS, DICTIONARY_TIMEOUT_MILLISECONDS};
+
+pub struct HttpDictionary {
+    dictionary_uri: String,
+    client: reqwest::Client,
+    cache: Mutex<LruCache<bool>>,
+    circuit_breaker: Mutex<CircuitBreaker>,
+}
+
+impl HttpDictionary {
+    pub fn new(dictionary_uri: String) -> Result<Self, Box<dyn std::error::Error>> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(DICTIONARY_TIMEOUT_MILLISECONDS))
+            .build()?;
+
+        Ok(Self {
+            dictionary_uri,
+            client,
+            cache: Mutex::new(LruCache::new(
+                DICTIONARY_CACHE_CAPACITY,
+                Duration::from_secs(DICTIONARY_CACHE_TTL_SECONDS),
+            )),
+            circuit_breaker: Mutex::new(CircuitBreaker::new(
+                DICTIONARY_CIRCUIT_FAILURE_THRESHOLD,
+                Duration::from_secs(DICTIONARY_CIRCUIT_COOLDOWN_SECONDS),
+            )),
+        })
+    }
+
+    async fn fetch_word(&self, word: &str) -> Result<bool, String> {
+        let url = format!("{}/{}", self.dictionary_uri, word);
+        let mut last_error = String::new();
+
+        for attempt in 0..=DICTIONARY_MAX_RETRIES {
+            if attempt > 0 {
+                let backoff = DICTIONARY_RETRY_BACKOFF_MILLISECONDS * u64::from(attempt);
+                actix::clock::sleep(Duration::from_millis(backoff)).await;
+            }
+
+            match self.client.get(url.as_str()).send().await {
+                Ok(res) if res.status().is_success() => return Ok(true),
+                Ok(res) if res.status() == reqwest::StatusCode::NOT_FOUND => return Ok(false),
+                Ok(res) => last_error = format!("Dictionary responded with {}", res.status()),
+                Err(err) => last_error = err.to_string(),
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+#[async_trait]
+impl Dictionary for HttpDictionary {
+    async fn word_exists(&self, word: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        if let Some(exists) = self.cache.lock().unwrap().get(word) {
+            return Ok(exists);
+        }
+        if !self.circuit_breaker.lock().unwrap().allow_request() {
+            return Err("Dictionary circuit breaker is open".into());
+        }
+
+        let result = self.fetch_word(word).await;
+        let mut circuit_breaker = self.circuit_breaker.lock().unwrap();
+        match result {
+            Ok(exists) => {
+                circuit_breaker.record_success();
+                self.cache.lock().unwrap().insert(word.to_string(), exists);
+                Ok(exists)
+            }
+            Err(err) => {
+                circuit_breaker.record_failure();
+                Err(err.into())
+            }
+        }
+    }
+}
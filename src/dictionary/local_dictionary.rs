@@ -0,0 +1,30 @@
+use std::fs;
+
+use async_trait::async_trait;
+
+use crate::dictionary::dictionary::Dictionary;
+use crate::dictionary::trie::Trie;
+
+pub struct LocalDictionary {
+    words: Trie,
+}
+
+impl LocalDictionary {
+    pub fn load(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let data = fs::read_to_string(path)?;
+        let mut words = Trie::new();
+        data.lines()
+            .map(|line| line.trim())
+            .filter(|word| !word.is_empty())
+            .for_each(|word| words.insert(&word.to_lowercase()));
+
+        Ok(Self { words })
+    }
+}
+
+#[async_trait]
+impl Dictionary for LocalDictionary {
+    async fn word_exists(&self, word: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(self.words.contains(&word.to_lowercase()))
+    }
+}
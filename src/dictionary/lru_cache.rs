@@ -0,0 +1,115 @@
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+struct CacheEntry<V> {
+    value: V,
+    inserted_at: Instant,
+    last_used: u64,
+}
+
+pub struct LruCache<V> {
+    capacity: usize,
+    ttl: Duration,
+    entries: HashMap<String, CacheEntry<V>>,
+    usage: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl<V: Clone> LruCache<V> {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: HashMap::new(),
+            usage: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    pub fn get(&mut self, key: &str) -> Option<V> {
+        let entry = self.entries.get(key)?;
+        if entry.inserted_at.elapsed() >= self.ttl {
+            self.remove(key);
+            return None;
+        }
+
+        self.tick += 1;
+        let entry = self.entries.get_mut(key)?;
+        self.usage.remove(&entry.last_used);
+        entry.last_used = self.tick;
+        self.usage.insert(self.tick, key.to_string());
+        Some(entry.value.clone())
+    }
+
+    pub fn insert(&mut self, key: String, value: V) {
+        self.remove(&key);
+        self.tick += 1;
+        self.usage.insert(self.tick, key.clone());
+        self.entries.insert(key, CacheEntry {
+            value,
+            inserted_at: Instant::now(),
+            last_used: self.tick,
+        });
+
+        while self.entries.len() > self.capacity {
+            let least_recently_used = match self.usage.first_key_value() {
+                Some((_, key)) => key.clone(),
+                None => break,
+            };
+            self.remove(&least_recently_used);
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.usage.remove(&entry.last_used);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_expire_after_the_ttl() {
+        let mut cache = LruCache::new(4, Duration::ZERO);
+        cache.insert("word".to_string(), true);
+        assert_eq!(cache.get("word"), None);
+        assert!(cache.entries.is_empty());
+        assert!(cache.usage.is_empty());
+    }
+
+    #[test]
+    fn entries_are_kept_within_the_ttl() {
+        let mut cache = LruCache::new(4, Duration::from_secs(60));
+        cache.insert("word".to_string(), true);
+        assert_eq!(cache.get("word"), Some(true));
+    }
+
+    #[test]
+    fn least_recently_used_entry_is_evicted() {
+        let mut cache = LruCache::new(2, Duration::from_secs(60));
+        cache.insert("first".to_string(), 1);
+        cache.insert("second".to_string(), 2);
+        assert_eq!(cache.get("first"), Some(1));
+        cache.insert("third".to_string(), 3);
+
+        assert_eq!(cache.get("second"), None);
+        assert_eq!(cache.get("first"), Some(1));
+        assert_eq!(cache.get("third"), Some(3));
+        assert_eq!(cache.entries.len(), cache.usage.len());
+    }
+
+    #[test]
+    fn reinserting_refreshes_an_entry() {
+        let mut cache = LruCache::new(2, Duration::from_secs(60));
+        cache.insert("first".to_string(), 1);
+        cache.insert("second".to_string(), 2);
+        cache.insert("first".to_string(), 10);
+        cache.insert("third".to_string(), 3);
+
+        assert_eq!(cache.get("first"), Some(10));
+        assert_eq!(cache.get("second"), None);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod circuit_breaker;
+#[allow(clippy::module_inception)]
+pub mod dictionary;
+pub mod dictionary_backend;
+pub mod dictionary_factory;
+pub mod http_dictionary;
+pub mod local_dictionary;
+pub mod lru_cache;
+pub mod trie;
@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    is_word: bool,
+}
+
+#[derive(Default)]
+pub struct Trie {
+    root: TrieNode,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, word: &str) {
+        let node = word.chars().fold(&mut self.root, |node, letter| {
+            node.children.entry(letter).or_default()
+        });
+        node.is_word = true;
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        let mut node = &self.root;
+        for letter in word.chars() {
+            node = match node.children.get(&letter) {
+                Some(child) => child,
+                None => { return false; }
+            };
+        }
+        node.is_word
+    }
+}
@@ -0,0 +1,30 @@
+use crate::model::letter::LetterDefinition;
+
+pub const ENGLISH_LETTERS: [LetterDefinition; 26] = [
+    LetterDefinition { letter: 'A', value: 1, frequency: 9 },
+    LetterDefinition { letter: 'B', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'C', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'D', value: 2, frequency: 4 },
+    LetterDefinition { letter: 'E', value: 1, frequency: 12 },
+    LetterDefinition { letter: 'F', value: 4, frequency: 2 },
+    LetterDefinition { letter: 'G', value: 2, frequency: 3 },
+    LetterDefinition { letter: 'H', value: 2, frequency: 2 },
+    LetterDefinition { letter: 'I', value: 1, frequency: 9 },
+    LetterDefinition { letter: 'J', value: 8, frequency: 1 },
+    LetterDefinition { letter: 'K', value: 5, frequency: 1 },
+    LetterDefinition { letter: 'L', value: 1, frequency: 4 },
+    LetterDefinition { letter: 'M', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'N', value: 1, frequency: 6 },
+    LetterDefinition { letter: 'O', value: 1, frequency: 8 },
+    LetterDefinition { letter: 'P', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'Q', value: 10, frequency: 1 },
+    LetterDefinition { letter: 'R', value: 1, frequency: 6 },
+    LetterDefinition { letter: 'S', value: 1, frequency: 4 },
+    LetterDefinition { letter: 'T', value: 1, frequency: 6 },
+    LetterDefinition { letter: 'U', value: 1, frequency: 4 },
+    LetterDefinition { letter: 'V', value: 4, frequency: 2 },
+    LetterDefinition { letter: 'W', value: 4, frequency: 2 },
+    LetterDefinition { letter: 'X', value: 8, frequency: 1 },
+    LetterDefinition { letter: 'Y', value: 4, frequency: 2 },
+    LetterDefinition { letter: 'Z', value: 10, frequency: 1 },
+];
@@ -0,0 +1,45 @@
+use crate::language::english::ENGLISH_LETTERS;
+use crate::language::polish::POLISH_LETTERS;
+use crate::model::language::Language;
+use crate::model::letter::LetterDefinition;
+
+pub struct LanguageDefinition {
+    pub language: Language,
+    pub code: &'static str,
+    pub letters: &'static [LetterDefinition],
+    pub vowels: &'static [char],
+}
+
+impl LanguageDefinition {
+    pub fn config_key(&self, key: &str) -> String {
+        if self.language == Language::default() {
+            return key.to_string();
+        }
+        format!("{}_{}", key, self.code)
+    }
+}
+
+const LANGUAGES: [LanguageDefinition; 2] = [
+    LanguageDefinition {
+        language: Language::English,
+        code: "en",
+        letters: &ENGLISH_LETTERS,
+        vowels: &['A', 'E', 'I', 'O', 'U'],
+    },
+    LanguageDefinition {
+        language: Language::Polish,
+        code: "pl",
+        letters: &POLISH_LETTERS,
+        vowels: &['A', 'Ą', 'E', 'Ę', 'I', 'O', 'Ó', 'U', 'Y'],
+    },
+];
+
+pub fn get_languages() -> &'static [LanguageDefinition] {
+    &LANGUAGES
+}
+
+pub fn get_language_definition(language: Language) -> &'static LanguageDefinition {
+    LANGUAGES.iter()
+        .find(|definition| definition.language == language)
+        .unwrap_or(&LANGUAGES[0])
+}
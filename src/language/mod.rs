@@ -0,0 +1,3 @@
+pub mod language_registry;
+mod english;
+mod polish;
@@ -0,0 +1,36 @@
+use crate::model::letter::LetterDefinition;
+
+pub const POLISH_LETTERS: [LetterDefinition; 32] = [
+    LetterDefinition { letter: 'A', value: 1, frequency: 9 },
+    LetterDefinition { letter: 'Ą', value: 5, frequency: 1 },
+    LetterDefinition { letter: 'B', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'C', value: 2, frequency: 3 },
+    LetterDefinition { letter: 'Ć', value: 6, frequency: 1 },
+    LetterDefinition { letter: 'D', value: 2, frequency: 3 },
+    LetterDefinition { letter: 'E', value: 1, frequency: 7 },
+    LetterDefinition { letter: 'Ę', value: 5, frequency: 1 },
+    LetterDefinition { letter: 'F', value: 5, frequency: 1 },
+    LetterDefinition { letter: 'G', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'H', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'I', value: 1, frequency: 8 },
+    LetterDefinition { letter: 'J', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'K', value: 2, frequency: 3 },
+    LetterDefinition { letter: 'L', value: 2, frequency: 3 },
+    LetterDefinition { letter: 'Ł', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'M', value: 2, frequency: 3 },
+    LetterDefinition { letter: 'N', value: 1, frequency: 5 },
+    LetterDefinition { letter: 'Ń', value: 7, frequency: 1 },
+    LetterDefinition { letter: 'O', value: 1, frequency: 6 },
+    LetterDefinition { letter: 'Ó', value: 5, frequency: 1 },
+    LetterDefinition { letter: 'P', value: 2, frequency: 3 },
+    LetterDefinition { letter: 'R', value: 1, frequency: 4 },
+    LetterDefinition { letter: 'S', value: 1, frequency: 4 },
+    LetterDefinition { letter: 'Ś', value: 5, frequency: 1 },
+    LetterDefinition { letter: 'T', value: 2, frequency: 3 },
+    LetterDefinition { letter: 'U', value: 3, frequency: 2 },
+    LetterDefinition { letter: 'W', value: 1, frequency: 4 },
+    LetterDefinition { letter: 'Y', value: 2, frequency: 4 },
+    LetterDefinition { letter: 'Z', value: 1, frequency: 5 },
+    LetterDefinition { letter: 'Ź', value: 9, frequency: 1 },
+    LetterDefinition { letter: 'Ż', value: 5, frequency: 1 },
+];
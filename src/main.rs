@@ -4,10 +4,22 @@ use actix_web::web::Data;
 use actix_web_httpauth::middleware::HttpAuthentication;
 
 use crate::authorization::bearer_auth::validate;
-use crate::controller::{facebook_controller, user_controller};
+use crate::controller::{facebook_controller, leaderboard_controller, match_controller, tournament_controller, user_controller};
+use crate::repository::mongo_db_achievement_repository::MongoDBAchievementRepository;
+use crate::repository::mongo_db_leaderboard_repository::MongoDBLeaderboardRepository;
+use crate::repository::mongo_db_match_repository::MongoDBMatchRepository;
+use crate::repository::mongo_db_player_stats_repository::MongoDBPlayerStatsRepository;
+use crate::repository::mongo_db_tournament_repository::MongoDBTournamentRepository;
 use crate::repository::mongo_db_user_repository::MongoDBUserRepository;
+use crate::service::achievement_service::AchievementService;
+use crate::service::dictionary_service::DictionaryService;
 use crate::service::facebook_service::FacebookService;
+use crate::service::leaderboard_service::LeaderboardService;
+use crate::service::match_service::MatchService;
+use crate::service::player_stats_service::PlayerStatsService;
+use crate::service::tournament_service::TournamentService;
 use crate::service::user_service::UserService;
+use crate::tournament::tournament_manager::TournamentManager;
 use crate::ws::room_manager::RoomManager;
 use crate::ws::ws_route::ws_route;
 
@@ -19,6 +31,9 @@ mod authorization;
 mod env;
 mod ws;
 mod util;
+mod dictionary;
+mod language;
+mod tournament;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
@@ -26,10 +41,35 @@ async fn main() -> std::io::Result<()> {
     let user_service = UserService::new(user_repository);
     let user_service = Data::new(user_service);
 
+    let match_repository = MongoDBMatchRepository::new().await.unwrap();
+    let match_service = MatchService::new(match_repository);
+    let match_service = Data::new(match_service);
+
+    let player_stats_repository = MongoDBPlayerStatsRepository::new().await.unwrap();
+    let player_stats_service = PlayerStatsService::new(player_stats_repository);
+    let player_stats_service = Data::new(player_stats_service);
+
+    let achievement_repository = MongoDBAchievementRepository::new().await.unwrap();
+    let achievement_service = AchievementService::new(achievement_repository);
+    let achievement_service = Data::new(achievement_service);
+
+    let leaderboard_repository = MongoDBLeaderboardRepository::new().await.unwrap();
+    let leaderboard_service = LeaderboardService::new(leaderboard_repository);
+    let leaderboard_service = Data::new(leaderboard_service);
+
+    let tournament_repository = MongoDBTournamentRepository::new().await.unwrap();
+    let tournament_service = TournamentService::new(tournament_repository);
+    let tournament_service = Data::new(tournament_service);
+
     let facebook_service = FacebookService::new();
     let facebook_service = Data::new(facebook_service);
 
-    let room_manager = Data::new(RoomManager::new().start());
+    let dictionary_service = DictionaryService::new().unwrap();
+    let dictionary_service = Data::new(dictionary_service);
+
+    let room_manager = RoomManager::new(user_service.clone(), match_service.clone(), player_stats_service.clone(), achievement_service.clone(), dictionary_service.languages()).start();
+    let tournament_manager = Data::new(TournamentManager::new(room_manager.clone(), tournament_service.clone()).start());
+    let room_manager = Data::new(room_manager);
 
     HttpServer::new(move || {
         let auth = HttpAuthentication::bearer(validate);
@@ -38,12 +78,40 @@ async fn main() -> std::io::Result<()> {
             .app_data(user_service.clone())
             .app_data(facebook_service.clone())
             .app_data(room_manager.clone())
+            .app_data(dictionary_service.clone())
+            .app_data(match_service.clone())
+            .app_data(tournament_service.clone())
+            .app_data(leaderboard_service.clone())
+            .app_data(player_stats_service.clone())
+            .app_data(achievement_service.clone())
+            .app_data(tournament_manager.clone())
             .service(
                 web::scope("/users")
                     .wrap(auth.clone())
                     .route("/{id}", web::get().to(user_controller::get_user::<MongoDBUserRepository>))
+                    .route("/{id}/stats", web::get().to(user_controller::get_user_stats::<MongoDBPlayerStatsRepository>))
+                    .route("/{id}/achievements", web::get().to(user_controller::get_user_achievements::<MongoDBAchievementRepository>))
                     .route("", web::post().to(user_controller::create_user::<MongoDBUserRepository>))
             )
+            .service(
+                web::scope("/matches")
+                    .wrap(auth.clone())
+                    .route("/{id}/replay", web::get().to(match_controller::get_replay::<MongoDBMatchRepository>))
+            )
+            .service(
+                web::scope("/leaderboards")
+                    .wrap(auth.clone())
+                    .route("/{kind}", web::get().to(leaderboard_controller::get_leaderboard::<MongoDBLeaderboardRepository>))
+                    .route("/{kind}/seasons/{season}", web::get().to(leaderboard_controller::get_season_archive::<MongoDBLeaderboardRepository>))
+            )
+            .service(
+                web::scope("/tournaments")
+                    .wrap(auth.clone())
+                    .route("", web::post().to(tournament_controller::create_tournament))
+                    .route("/{id}", web::get().to(tournament_controller::get_tournament::<MongoDBTournamentRepository>))
+                    .route("/{id}/register", web::post().to(tournament_controller::register))
+                    .route("/{id}/start", web::post().to(tournament_controller::start_tournament))
+            )
             .service(
                 web::scope("/auth/facebook")
                     .route("/callback", web::get().to(facebook_controller::facebook_callback))
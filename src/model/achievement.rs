@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Eq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum AchievementId {
+    FirstVictory,
+    HeavyHitter,
+    Untouchable,
+    RareLetters,
+    Wordsmith,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum AchievementCondition {
+    WinMatch,
+    WinWithoutTakingDamage,
+    WordDamageAtLeast(u32),
+    WordContainsLetters(&'static [char]),
+    WordLengthAtLeast(usize),
+}
+
+#[derive(Debug)]
+pub struct AchievementDefinition {
+    pub id: AchievementId,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub condition: AchievementCondition,
+}
+
+pub const ACHIEVEMENTS: [AchievementDefinition; 5] = [
+    AchievementDefinition {
+        id: AchievementId::FirstVictory,
+        name: "First Victory",
+        description: "Win a match",
+        condition: AchievementCondition::WinMatch,
+    },
+    AchievementDefinition {
+        id: AchievementId::HeavyHitter,
+        name: "Heavy Hitter",
+        description: "Deal 50 damage with one word",
+        condition: AchievementCondition::WordDamageAtLeast(50),
+    },
+    AchievementDefinition {
+        id: AchievementId::Untouchable,
+        name: "Untouchable",
+        description: "Win a match without taking damage",
+        condition: AchievementCondition::WinWithoutTakingDamage,
+    },
+    AchievementDefinition {
+        id: AchievementId::RareLetters,
+        name: "Rare Letters",
+        description: "Play a word using Q and Z",
+        condition: AchievementCondition::WordContainsLetters(&['q', 'z']),
+    },
+    AchievementDefinition {
+        id: AchievementId::Wordsmith,
+        name: "Wordsmith",
+        description: "Play a word with at least 8 letters",
+        condition: AchievementCondition::WordLengthAtLeast(8),
+    },
+];
+
+impl AchievementId {
+    pub fn key(self) -> &'static str {
+        match self {
+            AchievementId::FirstVictory => "first_victory",
+            AchievementId::HeavyHitter => "heavy_hitter",
+            AchievementId::Untouchable => "untouchable",
+            AchievementId::RareLetters => "rare_letters",
+            AchievementId::Wordsmith => "wordsmith",
+        }
+    }
+
+    pub fn definition(self) -> &'static AchievementDefinition {
+        ACHIEVEMENTS.iter()
+            .find(|definition| definition.id == self)
+            .expect("every achievement has a definition")
+    }
+}
+
+impl AchievementCondition {
+    pub fn is_met_by_word(&self, word: &str) -> bool {
+        match self {
+            AchievementCondition::WordContainsLetters(letters) => {
+                let word = word.to_lowercase();
+                letters.iter().all(|letter| word.contains(*letter))
+            }
+            AchievementCondition::WordLengthAtLeast(length) => word.chars().count() >= *length,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct UserAchievements {
+    pub id: String,
+    #[serde(default)]
+    pub achievements: Vec<AchievementId>,
+    /// First unlock time of each achievement, keyed by `AchievementId::key`.
+    #[serde(default)]
+    pub unlocked_at: HashMap<String, DateTime<Utc>>,
+}
+
+impl UserAchievements {
+    pub fn badges(&self) -> Vec<Badge> {
+        self.achievements.iter()
+            .map(|&id| Badge::new(id, self.unlocked_at.get(id.key()).copied().unwrap_or_default()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Badge {
+    pub id: AchievementId,
+    pub name: String,
+    pub description: String,
+    pub unlocked_at: DateTime<Utc>,
+}
+
+impl Badge {
+    pub fn new(id: AchievementId, unlocked_at: DateTime<Utc>) -> Self {
+        let definition = id.definition();
+        Self {
+            id,
+            name: definition.name.to_string(),
+            description: definition.description.to_string(),
+            unlocked_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_match_the_serialized_ids() {
+        for definition in ACHIEVEMENTS.iter() {
+            let serialized = serde_json::to_value(definition.id).unwrap();
+            assert_eq!(serialized.as_str(), Some(definition.id.key()));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum BotDifficulty {
+    Easy,
+    Medium,
+    Hard,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum EmoteId {
+    Hello,
+    GoodLuck,
+    WellPlayed,
+    Thanks,
+    Oops,
+    Wow,
+    GoodGame,
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::util::constants::{LEADERBOARD_DEFAULT_PAGE_SIZE, LEADERBOARD_MAX_PAGE_SIZE};
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardKind {
+    Rating,
+    Wins,
+    Damage,
+    LongestWord,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardPeriod {
+    #[default]
+    AllTime,
+    Seasonal,
+    Weekly,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct LeaderboardQuery {
+    pub period: LeaderboardPeriod,
+    pub page: usize,
+    pub page_size: usize,
+}
+
+impl LeaderboardQuery {
+    pub fn page(&self) -> usize {
+        self.page.max(1)
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size.clamp(1, LEADERBOARD_MAX_PAGE_SIZE)
+    }
+}
+
+impl Default for LeaderboardQuery {
+    fn default() -> Self {
+        Self {
+            period: LeaderboardPeriod::default(),
+            page: 1,
+            page_size: LEADERBOARD_DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub user_id: String,
+    pub name: String,
+    pub value: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Leaderboard {
+    pub kind: LeaderboardKind,
+    pub period: LeaderboardPeriod,
+    pub season: Option<u32>,
+    pub page: usize,
+    pub page_size: usize,
+    pub total: usize,
+    pub entries: Vec<LeaderboardEntry>,
+    pub me: Option<LeaderboardEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LeaderboardArchive {
+    pub id: String,
+    pub kind: LeaderboardKind,
+    pub season: u32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Season {
+    pub number: u32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
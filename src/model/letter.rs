@@ -1,7 +1,28 @@
 use serde::{Deserialize, Serialize};
 
+use crate::model::tile_modifier::TileModifier;
+
 #[derive(PartialEq, Debug, Deserialize, Serialize, Clone)]
 pub struct Letter {
     pub letter: char,
     pub value: u32,
-}
\ No newline at end of file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modifier: Option<TileModifier>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct LetterDefinition {
+    pub letter: char,
+    pub value: u32,
+    pub frequency: u32,
+}
+
+impl LetterDefinition {
+    pub fn to_letter(self) -> Letter {
+        Letter {
+            letter: self.letter,
+            value: self.value,
+            modifier: None,
+        }
+    }
+}
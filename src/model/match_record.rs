@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::model::room_rules::RoomRules;
+use crate::model::user::User;
+use crate::model::ws_response::WsResponse;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MatchRecord {
+    pub id: String,
+    pub seed: u64,
+    pub rules: RoomRules,
+    pub users: Vec<User>,
+    #[serde(with = "crate::util::bson_date_time")]
+    pub created_at: DateTime<Utc>,
+    pub events: Vec<MatchEvent>,
+    #[serde(default)]
+    pub players: Vec<MatchPlayerSummary>,
+    #[serde(default)]
+    pub ranked: bool,
+    #[serde(default)]
+    pub practice: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MatchPlayerSummary {
+    pub user_id: String,
+    pub name: String,
+    pub bot: bool,
+    pub won: bool,
+    pub place: usize,
+    pub rating: Option<i32>,
+    pub damage_dealt: u32,
+    pub longest_word: String,
+    pub longest_word_length: usize,
+    pub words_submitted: u32,
+    pub words_accepted: u32,
+    pub highest_damage_word: Option<WordDamage>,
+    pub letters_used: HashMap<String, u32>,
+    pub turn_millis: u64,
+    pub timed_turns: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WordDamage {
+    pub word: String,
+    pub damage: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MatchEvent {
+    pub timestamp: DateTime<Utc>,
+    pub offset_millis: u64,
+    pub player_index: Option<usize>,
+    pub event: WsResponse,
+}
+
+impl MatchRecord {
+    /// The replay as a user may see it: public events plus their own private ones, without emails.
+    pub fn visible_to(mut self, user_id: &str) -> MatchRecord {
+        let player_index = self.users.iter().position(|user| user.id == user_id);
+        self.events.retain(|match_event| match_event.player_index.is_none() || match_event.player_index == player_index);
+        for user in self.users.iter_mut() {
+            user.email.clear();
+        }
+        for match_event in self.events.iter_mut() {
+            strip_emails(&mut match_event.event);
+        }
+        self
+    }
+}
+
+fn strip_emails(event: &mut WsResponse) {
+    let users: Vec<&mut User> = match event {
+        WsResponse::StartPreparationTime(start_preparation_time) => start_preparation_time.users.iter_mut().collect(),
+        WsResponse::LobbyState(lobby_state) => lobby_state.users.iter_mut().collect(),
+        WsResponse::GameOver(game_over) => game_over.winner.iter_mut()
+            .chain(game_over.players.iter_mut().map(|player| &mut player.user))
+            .collect(),
+        _ => Vec::new(),
+    };
+    for user in users {
+        user.email.clear();
+    }
+}
@@ -6,3 +6,17 @@ pub mod letter;
 pub mod ws_request;
 pub mod player_session_messages;
 pub mod room_manager_messages;
+pub mod room_rules;
+pub mod emote_id;
+pub mod bot_difficulty;
+pub mod language;
+pub mod replay_speed;
+pub mod match_record;
+pub mod word_submission;
+pub mod tile_modifier;
+pub mod status_effect;
+pub mod tournament;
+pub mod tournament_manager_messages;
+pub mod leaderboard;
+pub mod player_stats;
+pub mod achievement;
@@ -1,8 +1,14 @@
 use actix::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::model::achievement::Badge;
+use crate::model::emote_id::EmoteId;
 use crate::model::letter::Letter;
-use crate::model::user::User;
+use crate::model::room_rules::RoomRules;
+use crate::model::status_effect::StatusEffect;
+use crate::model::tournament::Tournament;
+use crate::model::tile_modifier::TileModifier;
+use crate::model::user::{PublicUser, User};
 
 #[derive(Message)]
 #[rtype(result = "()")]
@@ -17,8 +23,11 @@ pub struct NextTurn {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct StartPreparationTime {
     pub seconds: u64,
+    pub rules: RoomRules,
     pub users: Vec<User>,
     pub letters: Vec<Letter>,
+    pub teams: Option<Vec<usize>>,
+    pub teammate_racks: Vec<TeammateRack>,
 }
 
 #[derive(Message)]
@@ -27,6 +36,7 @@ pub struct StartPreparationTime {
 pub struct WordCreated {
     pub player_index: usize,
     pub word: String,
+    pub target_index: Option<usize>,
 }
 
 #[derive(Message)]
@@ -34,6 +44,7 @@ pub struct WordCreated {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CheckWordExisting {
     pub player_index: usize,
+    pub target_index: Option<usize>,
     pub word: String,
 }
 
@@ -46,21 +57,20 @@ pub struct CanRollDice {
 
 #[derive(Message)]
 #[rtype(result = "()")]
-pub struct WordDoesNotExist;
+pub struct WordDoesNotExist {
+    pub word: String,
+}
 
 #[derive(Message, Clone)]
 #[rtype(result = "()")]
 pub struct WordExists {
     pub word: String,
     pub player_index: usize,
-    pub damage: u32,
 }
 
 #[derive(Message)]
 #[rtype(result = "()")]
-pub struct DiceRolled {
-    pub word_exists_event: WordExists,
-}
+pub struct DiceRolled;
 
 #[derive(Message)]
 #[rtype(result = "()")]
@@ -68,6 +78,8 @@ pub struct DiceRolled {
 pub struct DamagePlayer {
     pub player_index: usize,
     pub damage: u32,
+    pub absorbed: u32,
+    pub health: u32,
 }
 
 #[derive(Message)]
@@ -75,6 +87,8 @@ pub struct DamagePlayer {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TakeDamage {
     pub damage: u32,
+    pub absorbed: u32,
+    pub health: u32,
     pub player_index: usize,
 }
 
@@ -83,4 +97,240 @@ pub struct TakeDamage {
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PlayerDead {
     pub player_index: usize,
-}
\ No newline at end of file
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GameOver {
+    pub winner: Option<User>,
+    pub players: Vec<PlayerResult>,
+    pub turns: u32,
+    pub duration_seconds: u64,
+    pub seed: u64,
+    pub winning_team: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PlayerResult {
+    pub user: User,
+    pub health: u32,
+    pub place: usize,
+    pub rating: Option<i32>,
+    pub team: Option<usize>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GameState {
+    pub room_id: String,
+    pub users: Vec<PublicUser>,
+    pub health: Vec<u32>,
+    pub letters: Vec<Letter>,
+    pub player_index: Option<usize>,
+    pub turn_player_index: usize,
+    pub turns: u32,
+    pub rules: RoomRules,
+    pub tiles_remaining: usize,
+    pub status_effects: Vec<Vec<StatusEffect>>,
+    pub teams: Option<Vec<usize>>,
+    pub teammate_racks: Vec<TeammateRack>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PlayerLeft {
+    pub player_index: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RoomCreated {
+    pub code: String,
+    pub rules: RoomRules,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RoomNotFound {
+    pub code: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ActiveRooms {
+    pub rooms: Vec<RoomSummary>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RoomSummary {
+    pub id: String,
+    pub users: Vec<PublicUser>,
+    pub health: Vec<u32>,
+    pub status_effects: Vec<Vec<StatusEffect>>,
+    pub turns: u32,
+    pub spectators: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ChatMessage {
+    pub user_id: String,
+    pub name: String,
+    pub message: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EmoteMessage {
+    pub user_id: String,
+    pub name: String,
+    pub emote: EmoteId,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LobbyState {
+    pub users: Vec<User>,
+    pub ready: Vec<bool>,
+    pub countdown_seconds: Option<u64>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ReadyCheckFailed;
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct RoomClosed;
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DictionaryUnavailable {
+    pub word: String,
+    pub seconds: u64,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TilesRemaining {
+    pub count: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WordValidated {
+    pub player_index: usize,
+    pub word: String,
+    pub valid: bool,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DiceRollResult {
+    pub target_index: usize,
+    pub face: u32,
+    pub multiplier: f64,
+    pub damage: u32,
+    pub new_letters: Vec<Letter>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PlayerRolledDice {
+    pub player_index: usize,
+    pub target_index: usize,
+    pub face: u32,
+    pub multiplier: f64,
+    pub damage: u32,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InvalidTarget {
+    pub target_user_id: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SpellEffect {
+    pub caster_index: usize,
+    pub target_index: usize,
+    pub modifier: TileModifier,
+    pub amount: u32,
+    #[serde(default)]
+    pub turns: u32,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HealPlayer {
+    pub player_index: usize,
+    pub amount: u32,
+    pub health: u32,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct StatusEffectsUpdated {
+    pub player_index: usize,
+    pub effects: Vec<StatusEffect>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LettersSilenced {
+    pub word: String,
+    pub letters: Vec<char>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TeammateRack {
+    pub player_index: usize,
+    pub letters: Vec<Letter>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TournamentUpdated {
+    pub tournament: Tournament,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TournamentMatchReady {
+    pub tournament_id: String,
+    pub match_id: usize,
+    pub code: String,
+    pub opponent: User,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AchievementUnlocked {
+    pub badge: Badge,
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::match_record::WordDamage;
+use crate::util::constants::FAVOURITE_LETTERS_COUNT;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default)]
+pub struct PlayerStats {
+    pub id: String,
+    pub games_played: u32,
+    pub wins: u32,
+    pub placements: Vec<u32>,
+    /// Finishes per place, keyed by the place as stored in the database.
+    #[serde(skip_serializing)]
+    pub placement_counts: HashMap<String, u32>,
+    pub words_submitted: u32,
+    pub words_accepted: u32,
+    pub rejection_rate: f64,
+    pub total_damage: u64,
+    pub average_damage: f64,
+    pub highest_damage_word: Option<WordDamage>,
+    pub letter_counts: HashMap<String, u32>,
+    pub favourite_letters: Vec<String>,
+    pub total_turn_millis: u64,
+    pub timed_turns: u32,
+    pub average_turn_seconds: f64,
+}
+
+impl PlayerStats {
+    pub fn new(id: String) -> Self {
+        Self {
+            id,
+            ..Self::default()
+        }
+    }
+
+    /// Fills in the fields that are computed from the stored counters.
+    pub fn with_derived_stats(mut self) -> Self {
+        self.placements = Vec::new();
+        for (place, count) in self.placement_counts.iter() {
+            let Some(placement) = place.parse::<usize>().ok().and_then(|place| place.checked_sub(1)) else {
+                continue;
+            };
+            if self.placements.len() <= placement {
+                self.placements.resize(placement + 1, 0);
+            }
+            self.placements[placement] = *count;
+        }
+        self.rejection_rate = ratio(f64::from(self.words_submitted.saturating_sub(self.words_accepted)), f64::from(self.words_submitted));
+        self.average_damage = ratio(self.total_damage as f64, f64::from(self.games_played));
+        self.average_turn_seconds = ratio(self.total_turn_millis as f64 / 1000.0, f64::from(self.timed_turns));
+
+        let mut letters: Vec<(&String, &u32)> = self.letter_counts.iter().collect();
+        letters.sort_by(|(letter_a, count_a), (letter_b, count_b)| count_b.cmp(count_a).then(letter_a.cmp(letter_b)));
+        self.favourite_letters = letters.into_iter()
+            .take(FAVOURITE_LETTERS_COUNT)
+            .map(|(letter, _)| letter.clone())
+            .collect();
+        self
+    }
+}
+
+fn ratio(value: f64, total: f64) -> f64 {
+    if total == 0.0 {
+        return 0.0;
+    }
+    value / total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derived_stats_are_computed_from_the_counters() {
+        let mut player_stats = PlayerStats::new("user".to_string());
+        player_stats.games_played = 4;
+        player_stats.words_submitted = 10;
+        player_stats.words_accepted = 8;
+        player_stats.total_damage = 120;
+        player_stats.placement_counts = HashMap::from([("1".to_string(), 3), ("3".to_string(), 1)]);
+        player_stats.letter_counts = HashMap::from([("a".to_string(), 5), ("b".to_string(), 5), ("c".to_string(), 9)]);
+
+        let player_stats = player_stats.with_derived_stats();
+
+        assert_eq!(player_stats.placements, vec![3, 0, 1]);
+        assert_eq!(player_stats.rejection_rate, 0.2);
+        assert_eq!(player_stats.average_damage, 30.0);
+        assert_eq!(&player_stats.favourite_letters[..3], ["c", "a", "b"]);
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy, Default)]
+pub enum ReplaySpeed {
+    #[default]
+    #[serde(rename = "1x")]
+    Normal,
+    #[serde(rename = "2x")]
+    Double,
+    #[serde(rename = "4x")]
+    Quadruple,
+}
+
+impl ReplaySpeed {
+    pub fn multiplier(&self) -> u64 {
+        match self {
+            ReplaySpeed::Normal => 1,
+            ReplaySpeed::Double => 2,
+            ReplaySpeed::Quadruple => 4,
+        }
+    }
+}
@@ -1,14 +1,21 @@
 use actix::prelude::*;
 
+use crate::model::bot_difficulty::BotDifficulty;
+use crate::model::emote_id::EmoteId;
+use crate::model::language::Language;
+use crate::model::room_rules::{RoomRules, RoomRulesChoice};
+use crate::model::tournament_manager_messages::TournamentMatchFinished;
 use crate::model::user::User;
 use crate::ws::player_session::PlayerSession;
+use crate::ws::session_addr::SessionAddr;
 
 #[derive(Message)]
 #[rtype(result = "()")]
 pub struct CreateWord {
     pub user: User,
     pub word: String,
-    pub session_addr: Addr<PlayerSession>,
+    pub target_user_id: Option<String>,
+    pub session_addr: SessionAddr,
 }
 
 #[derive(Message)]
@@ -19,28 +26,146 @@ pub struct RoomNextTurn {
 
 #[derive(Message)]
 #[rtype(result = "()")]
-pub struct RoomDamagePlayer {
+pub struct RoomRollDice {
     pub user: User,
-    pub player_index: usize,
-    pub damage: u32,
 }
 
 #[derive(Message)]
 #[rtype(result = "()")]
-pub struct RoomPlayerDead {
+pub struct Join {
     pub user: User,
-    pub player_index: usize,
+    pub session_addr: Addr<PlayerSession>,
+    pub language: Language,
 }
 
 #[derive(Message)]
 #[rtype(result = "()")]
-pub struct Join {
+pub struct RoomNextTurnTimeoutInit {
+    pub user: User,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Leave {
     pub user: User,
     pub session_addr: Addr<PlayerSession>,
 }
 
 #[derive(Message)]
 #[rtype(result = "()")]
-pub struct RoomNextTurnTimeoutInit {
+pub struct RoomForfeitPlayer {
+    pub user: User,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct CreatePrivateRoom {
+    pub user: User,
+    pub session_addr: Addr<PlayerSession>,
+    pub rules: Option<RoomRulesChoice>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct JoinRoom {
     pub user: User,
-}
\ No newline at end of file
+    pub session_addr: Addr<PlayerSession>,
+    pub code: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct StartPrivateRoom {
+    pub user: User,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct JoinRanked {
+    pub user: User,
+    pub session_addr: Addr<PlayerSession>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Spectate {
+    pub room_id: String,
+    pub session_addr: Addr<PlayerSession>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct StopSpectating {
+    pub session_addr: Addr<PlayerSession>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct ListRooms {
+    pub session_addr: Addr<PlayerSession>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct RoomChat {
+    pub user: User,
+    pub message: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct RoomEmote {
+    pub user: User,
+    pub emote: EmoteId,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct RoomReady {
+    pub user: User,
+    pub ready: bool,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct RoomCountdownFinished {
+    pub room_id: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct RoomReadyCheckExpired {
+    pub room_id: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Practice {
+    pub user: User,
+    pub session_addr: Addr<PlayerSession>,
+    pub difficulty: BotDifficulty,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct RoomWordCheckFailed {
+    pub user: User,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct RoomWordValidated {
+    pub user: User,
+    pub word: String,
+    pub target_index: Option<usize>,
+}
+
+#[derive(Message)]
+#[rtype(result = "String")]
+pub struct CreateTournamentRoom {
+    pub tournament_id: String,
+    pub match_id: usize,
+    pub users: Vec<User>,
+    pub rules: RoomRules,
+    pub result_recipient: Recipient<TournamentMatchFinished>,
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::language::Language;
+use crate::model::tile_modifier::TileModifierRates;
+use crate::util::constants::TEAM_MODE_PLAYERS;
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct RoomRules {
+    pub max_players: usize,
+    pub rack_size: usize,
+    pub preparation_seconds: u64,
+    pub turn_seconds: u64,
+    pub roll_dice_seconds: u64,
+    pub starting_health: u32,
+    pub damage_multiplier: f64,
+    pub language: Language,
+    pub tile_counts: Option<HashMap<char, u32>>,
+    pub return_used_tiles: bool,
+    pub min_vowels: Option<usize>,
+    pub tile_modifier_rates: TileModifierRates,
+    pub team_mode: bool,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum RoomRulesPreset {
+    Standard,
+    Blitz,
+    Duel,
+    Teams,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(untagged)]
+pub enum RoomRulesChoice {
+    Preset(RoomRulesPreset),
+    Custom(RoomRules),
+}
+
+impl RoomRules {
+    pub fn from_preset(preset: RoomRulesPreset) -> Self {
+        match preset {
+            RoomRulesPreset::Standard => Self {
+                max_players: 4,
+                rack_size: 14,
+                preparation_seconds: 20,
+                turn_seconds: 20,
+                roll_dice_seconds: 20,
+                starting_health: 100,
+                damage_multiplier: 1.0,
+                language: Language::default(),
+                tile_counts: None,
+                return_used_tiles: true,
+                min_vowels: None,
+                tile_modifier_rates: TileModifierRates::default(),
+                team_mode: false,
+            },
+            RoomRulesPreset::Blitz => Self {
+                max_players: 4,
+                rack_size: 10,
+                preparation_seconds: 10,
+                turn_seconds: 10,
+                roll_dice_seconds: 5,
+                starting_health: 60,
+                damage_multiplier: 1.5,
+                language: Language::default(),
+                tile_counts: None,
+                return_used_tiles: true,
+                min_vowels: None,
+                tile_modifier_rates: TileModifierRates::default(),
+                team_mode: false,
+            },
+            RoomRulesPreset::Duel => Self {
+                max_players: 2,
+                ..Self::from_preset(RoomRulesPreset::Standard)
+            },
+            RoomRulesPreset::Teams => Self {
+                max_players: TEAM_MODE_PLAYERS,
+                team_mode: true,
+                ..Self::from_preset(RoomRulesPreset::Standard)
+            },
+        }
+    }
+
+    pub fn from_choice(choice: Option<RoomRulesChoice>) -> Self {
+        match choice {
+            Some(RoomRulesChoice::Preset(preset)) => Self::from_preset(preset),
+            Some(RoomRulesChoice::Custom(rules)) if rules.is_valid() => rules,
+            _ => Self::default(),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        (2..=8).contains(&self.max_players)
+            && (1..=26).contains(&self.rack_size)
+            && (1..=300).contains(&self.preparation_seconds)
+            && (1..=300).contains(&self.turn_seconds)
+            && (1..=300).contains(&self.roll_dice_seconds)
+            && (1..=1000).contains(&self.starting_health)
+            && self.damage_multiplier > 0.0
+            && self.damage_multiplier <= 10.0
+            && self.tile_modifier_rates.is_valid()
+            && (!self.team_mode || self.max_players == TEAM_MODE_PLAYERS)
+            && self.min_vowels.is_none_or(|min_vowels| min_vowels <= self.rack_size)
+            && self.tile_counts.as_ref().is_none_or(|tile_counts| tile_counts.values().all(|&count| count <= 100))
+    }
+
+    pub fn apply_damage_multiplier(&self, damage: u32) -> u32 {
+        (f64::from(damage) * self.damage_multiplier).round() as u32
+    }
+}
+
+impl Default for RoomRules {
+    fn default() -> Self {
+        Self::from_preset(RoomRulesPreset::Standard)
+    }
+}
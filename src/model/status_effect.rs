@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusEffectKind {
+    Shield,
+    Poison,
+    Freeze,
+    Regeneration,
+    Silence,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone)]
+pub struct StatusEffect {
+    pub kind: StatusEffectKind,
+    pub amount: u32,
+    pub turns: u32,
+}
+
+impl StatusEffect {
+    pub fn merge(&mut self, other: &StatusEffect) {
+        self.amount = match self.kind {
+            StatusEffectKind::Shield => self.amount + other.amount,
+            _ => self.amount.max(other.amount),
+        };
+        self.turns = self.turns.max(other.turns);
+    }
+}
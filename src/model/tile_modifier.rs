@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TileModifier {
+    DoubleLetter,
+    TripleWord,
+    Heal,
+    Shield,
+    Poison,
+    Freeze,
+    Regeneration,
+    Silence,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone)]
+#[serde(default)]
+pub struct TileModifierRates {
+    pub double_letter: f64,
+    pub triple_word: f64,
+    pub heal: f64,
+    pub shield: f64,
+    pub poison: f64,
+    pub freeze: f64,
+    pub regeneration: f64,
+    pub silence: f64,
+}
+
+impl TileModifierRates {
+    pub fn is_valid(&self) -> bool {
+        let rates = self.rates();
+        rates.iter().all(|(_, rate)| (0.0..=1.0).contains(rate))
+            && rates.iter().map(|(_, rate)| rate).sum::<f64>() <= 1.0
+    }
+
+    pub fn pick(&self, roll: f64) -> Option<TileModifier> {
+        let mut threshold = 0.0;
+        for (modifier, rate) in self.rates() {
+            threshold += rate;
+            if roll < threshold {
+                return Some(modifier);
+            }
+        }
+        None
+    }
+
+    fn rates(&self) -> [(TileModifier, f64); 8] {
+        [
+            (TileModifier::DoubleLetter, self.double_letter),
+            (TileModifier::TripleWord, self.triple_word),
+            (TileModifier::Heal, self.heal),
+            (TileModifier::Shield, self.shield),
+            (TileModifier::Poison, self.poison),
+            (TileModifier::Freeze, self.freeze),
+            (TileModifier::Regeneration, self.regeneration),
+            (TileModifier::Silence, self.silence),
+        ]
+    }
+}
+
+impl Default for TileModifierRates {
+    fn default() -> Self {
+        Self {
+            double_letter: 0.04,
+            triple_word: 0.01,
+            heal: 0.02,
+            shield: 0.02,
+            poison: 0.02,
+            freeze: 0.01,
+            regeneration: 0.01,
+            silence: 0.01,
+        }
+    }
+}
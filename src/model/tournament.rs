@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::model::room_rules::{RoomRules, RoomRulesChoice};
+use crate::model::user::User;
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TournamentFormat {
+    SingleElimination,
+    DoubleElimination,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TournamentStatus {
+    Registration,
+    InProgress,
+    Finished,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TournamentBracket {
+    Winners,
+    Losers,
+    GrandFinal,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TournamentMatchStatus {
+    Pending,
+    Ready,
+    InProgress,
+    Finished,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone, Copy)]
+pub struct MatchSlot {
+    pub match_id: usize,
+    pub slot: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TournamentMatch {
+    pub id: usize,
+    pub bracket: TournamentBracket,
+    pub round: usize,
+    pub players: [Option<User>; 2],
+    pub resolved: [bool; 2],
+    pub winner_to: Option<MatchSlot>,
+    pub loser_to: Option<MatchSlot>,
+    pub status: TournamentMatchStatus,
+    pub room_code: Option<String>,
+    pub winner: Option<User>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Tournament {
+    pub id: String,
+    pub name: String,
+    pub format: TournamentFormat,
+    pub max_players: usize,
+    pub rules: RoomRules,
+    pub status: TournamentStatus,
+    pub players: Vec<User>,
+    pub matches: Vec<TournamentMatch>,
+    pub winner: Option<User>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Tournament {
+    pub fn is_participant(&self, user_id: &str) -> bool {
+        self.players.iter().any(|player| player.id == user_id)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CreateTournamentRequest {
+    pub name: String,
+    pub max_players: usize,
+    pub format: TournamentFormat,
+    pub rules: Option<RoomRulesChoice>,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum TournamentError {
+    NotFound,
+    Forbidden,
+    InvalidSettings,
+    RegistrationClosed,
+    TournamentFull,
+    AlreadyRegistered,
+    NotEnoughPlayers,
+}
+
+impl TournamentError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            TournamentError::NotFound => "Tournament not found",
+            TournamentError::Forbidden => "Only admins can manage tournaments",
+            TournamentError::InvalidSettings => "Invalid tournament settings",
+            TournamentError::RegistrationClosed => "Tournament registration is closed",
+            TournamentError::TournamentFull => "Tournament is full",
+            TournamentError::AlreadyRegistered => "Player is already registered",
+            TournamentError::NotEnoughPlayers => "Not enough players to start the tournament",
+        }
+    }
+}
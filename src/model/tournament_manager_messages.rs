@@ -0,0 +1,54 @@
+use actix::prelude::*;
+
+use crate::model::tournament::{CreateTournamentRequest, Tournament, TournamentError};
+use crate::model::user::User;
+use crate::ws::player_session::PlayerSession;
+
+#[derive(Message)]
+#[rtype(result = "Result<Tournament, TournamentError>")]
+pub struct CreateTournament {
+    pub user: User,
+    pub request: CreateTournamentRequest,
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Tournament, TournamentError>")]
+pub struct RegisterForTournament {
+    pub user: User,
+    pub tournament_id: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Tournament, TournamentError>")]
+pub struct StartTournament {
+    pub user: User,
+    pub tournament_id: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "Option<Tournament>")]
+pub struct GetTournament {
+    pub tournament_id: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct TournamentMatchFinished {
+    pub tournament_id: String,
+    pub match_id: usize,
+    pub winner: Option<User>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct SubscribeTournaments {
+    pub user: User,
+    pub session_addr: Addr<PlayerSession>,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct UnsubscribeTournaments {
+    pub user: User,
+    pub session_addr: Addr<PlayerSession>,
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::util::constants::DEFAULT_RATING;
+
 #[derive(PartialEq, Debug, Deserialize, Serialize, Clone)]
 pub struct User {
     pub id: String,
@@ -7,4 +9,28 @@ pub struct User {
     pub email: String,
     pub photo: String,
     pub provider: String,
-}
\ No newline at end of file
+    #[serde(default = "default_rating")]
+    pub rating: i32,
+}
+
+fn default_rating() -> i32 {
+    DEFAULT_RATING
+}
+
+/// What other players and spectators get to see about a user.
+#[derive(PartialEq, Debug, Deserialize, Serialize, Clone)]
+pub struct PublicUser {
+    pub id: String,
+    pub name: String,
+    pub rating: i32,
+}
+
+impl From<&User> for PublicUser {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id.clone(),
+            name: user.name.clone(),
+            rating: user.rating,
+        }
+    }
+}
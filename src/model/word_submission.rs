@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(untagged)]
+pub enum WordSubmission {
+    Word(String),
+    Targeted {
+        word: String,
+        target_user_id: Option<String>,
+    },
+}
+
+impl WordSubmission {
+    pub fn into_parts(self) -> (String, Option<String>) {
+        match self {
+            WordSubmission::Word(word) => (word, None),
+            WordSubmission::Targeted { word, target_user_id } => (word, target_user_id),
+        }
+    }
+}
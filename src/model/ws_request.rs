@@ -1,9 +1,31 @@
 use serde::{Deserialize, Serialize};
 
+use crate::model::bot_difficulty::BotDifficulty;
+use crate::model::emote_id::EmoteId;
+use crate::model::language::Language;
+use crate::model::replay_speed::ReplaySpeed;
+use crate::model::room_rules::RoomRulesChoice;
+use crate::model::word_submission::WordSubmission;
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(tag = "type", content = "content")]
 pub enum WsRequest {
-    Join,
-    CreateWord(String),
+    Join(Option<Language>),
+    JoinRanked,
+    CreateWord(WordSubmission),
     RollDice,
+    CreatePrivateRoom(Option<RoomRulesChoice>),
+    JoinRoom { code: String },
+    StartGame,
+    Spectate { room_id: String },
+    StopSpectating,
+    ListRooms,
+    Chat(String),
+    Emote(EmoteId),
+    Mute { user_id: String },
+    Unmute { user_id: String },
+    Ready,
+    Unready,
+    Practice(BotDifficulty),
+    Replay { match_id: String, speed: Option<ReplaySpeed> },
 }
\ No newline at end of file
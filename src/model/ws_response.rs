@@ -1,7 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::model::letter::Letter;
-use crate::model::player_session_messages::{CanRollDice, DamagePlayer, NextTurn, PlayerDead, StartPreparationTime, TakeDamage, WordCreated};
+use crate::model::player_session_messages::{AchievementUnlocked, ActiveRooms, CanRollDice, ChatMessage, DamagePlayer, DiceRollResult, DictionaryUnavailable, EmoteMessage, GameOver, GameState, HealPlayer, InvalidTarget, LettersSilenced, LobbyState, NextTurn, PlayerDead, PlayerLeft, PlayerRolledDice, ReadyCheckFailed, RoomCreated, RoomNotFound, SpellEffect, StartPreparationTime, StatusEffectsUpdated, TeammateRack, TournamentMatchReady, TournamentUpdated, TakeDamage, TilesRemaining, WordCreated, WordValidated};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(tag = "type", content = "content")]
@@ -10,14 +9,61 @@ pub enum WsResponse {
     NextTurn(NextTurn),
     WordCreated(WordCreated),
     CanRollDice(CanRollDice),
-    DiceRolledResponse(DiceRolledResponse),
+    DiceRolledResponse(DiceRollResult),
+    PlayerRolledDice(PlayerRolledDice),
     DamagePlayer(DamagePlayer),
     TakeDamage(TakeDamage),
     PlayerDead(PlayerDead),
+    GameOver(GameOver),
+    GameState(GameState),
+    PlayerLeft(PlayerLeft),
+    RoomCreated(RoomCreated),
+    RoomNotFound(RoomNotFound),
+    ActiveRooms(ActiveRooms),
+    Chat(ChatMessage),
+    Emote(EmoteMessage),
+    LobbyState(LobbyState),
+    ReadyCheckFailed(ReadyCheckFailed),
+    DictionaryUnavailable(DictionaryUnavailable),
+    TilesRemaining(TilesRemaining),
+    WordValidated(WordValidated),
+    InvalidTarget(InvalidTarget),
+    SpellEffect(SpellEffect),
+    HealPlayer(HealPlayer),
+    StatusEffectsUpdated(StatusEffectsUpdated),
+    LettersSilenced(LettersSilenced),
+    TeammateRack(TeammateRack),
+    TournamentUpdated(TournamentUpdated),
+    TournamentMatchReady(TournamentMatchReady),
+    AchievementUnlocked(AchievementUnlocked),
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
-pub struct DiceRolledResponse {
-    pub amount: usize,
-    pub new_letters: Vec<Letter>,
-}
\ No newline at end of file
+macro_rules! ws_response_from {
+    ($($message:ident => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$message> for WsResponse {
+                fn from(msg: $message) -> Self {
+                    WsResponse::$variant(msg)
+                }
+            }
+        )*
+    };
+}
+
+ws_response_from!(
+    NextTurn => NextTurn,
+    WordCreated => WordCreated,
+    DamagePlayer => DamagePlayer,
+    PlayerDead => PlayerDead,
+    GameOver => GameOver,
+    PlayerLeft => PlayerLeft,
+    PlayerRolledDice => PlayerRolledDice,
+    ChatMessage => Chat,
+    EmoteMessage => Emote,
+    LobbyState => LobbyState,
+    TilesRemaining => TilesRemaining,
+    WordValidated => WordValidated,
+    SpellEffect => SpellEffect,
+    HealPlayer => HealPlayer,
+    StatusEffectsUpdated => StatusEffectsUpdated,
+);
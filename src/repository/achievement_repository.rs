@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+
+use crate::model::achievement::{AchievementId, UserAchievements};
+
+#[async_trait::async_trait]
+pub trait AchievementRepository {
+    async fn find_by_id(&self, id: &str) -> Option<UserAchievements>;
+    /// Adds the achievements in one atomic upsert and returns the ones the user already had.
+    async fn unlock(&self, id: &str, achievement_ids: &[AchievementId], unlocked_at: DateTime<Utc>) -> Vec<AchievementId>;
+}
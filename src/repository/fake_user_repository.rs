@@ -1,6 +1,7 @@
 use crate::model::user::User;
 use crate::repository::repository::Repository;
 
+#[allow(dead_code)]
 pub struct FakeUserRepository {
     users: Vec<User>,
 }
@@ -20,4 +21,8 @@ impl Repository<User> for FakeUserRepository {
     async fn save(&self, user: User) {
         println!("Fake save: {:?}", user);
     }
+
+    async fn update(&self, user: User) {
+        println!("Fake update: {:?}", user);
+    }
 }
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+
+use crate::model::leaderboard::{LeaderboardArchive, LeaderboardEntry, LeaderboardKind};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StandingsWindow {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+#[async_trait::async_trait]
+pub trait LeaderboardRepository {
+    async fn find_standings(
+        &self,
+        kind: LeaderboardKind,
+        window: StandingsWindow,
+        skip: usize,
+        limit: Option<usize>,
+    ) -> Vec<LeaderboardEntry>;
+    async fn count_standings(&self, kind: LeaderboardKind, window: StandingsWindow) -> usize;
+    async fn find_standing(&self, kind: LeaderboardKind, window: StandingsWindow, user_id: &str) -> Option<LeaderboardEntry>;
+    async fn find_archive(&self, id: &str) -> Option<LeaderboardArchive>;
+    async fn save_archive(&self, archive: LeaderboardArchive);
+}
@@ -1,3 +1,12 @@
+#[allow(clippy::module_inception)]
 pub mod repository;
 pub mod fake_user_repository;
-pub mod mongo_db_user_repository;
\ No newline at end of file
+pub mod mongo_db_user_repository;
+pub mod mongo_db_match_repository;
+pub mod mongo_db_tournament_repository;
+pub mod leaderboard_repository;
+pub mod mongo_db_leaderboard_repository;
+pub mod player_stats_repository;
+pub mod mongo_db_player_stats_repository;
+pub mod achievement_repository;
+pub mod mongo_db_achievement_repository;
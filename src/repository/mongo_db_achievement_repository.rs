@@ -0,0 +1,57 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use mongodb::bson::{doc, Document};
+use mongodb::{Client, IndexModel};
+use mongodb::options::{ClientOptions, FindOneAndUpdateOptions, IndexOptions, ReturnDocument};
+
+use crate::model::achievement::{AchievementId, UserAchievements};
+use crate::repository::achievement_repository::AchievementRepository;
+
+pub struct MongoDBAchievementRepository {
+    collection: mongodb::Collection<UserAchievements>,
+}
+
+impl MongoDBAchievementRepository {
+    pub async fn new() -> Result<Self, mongodb::error::Error> {
+        let client_options = ClientOptions::parse("mongodb://127.0.0.1:27017").await?;
+        let client = Client::with_options(client_options)?;
+        let db = client.database("spell-fight-database");
+        let collection = db.collection::<UserAchievements>("achievements");
+        // Concurrent upserts for a new user must not create two documents.
+        let id_index = IndexModel::builder()
+            .keys(doc! { "id": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+        collection.create_index(id_index, None).await?;
+        Ok(Self { collection })
+    }
+}
+
+#[async_trait::async_trait]
+impl AchievementRepository for MongoDBAchievementRepository {
+    async fn find_by_id(&self, id: &str) -> Option<UserAchievements> {
+        let filter = doc! { "id": id };
+        self.collection.find_one(filter, None).await.unwrap()
+    }
+
+    async fn unlock(&self, id: &str, achievement_ids: &[AchievementId], unlocked_at: DateTime<Utc>) -> Vec<AchievementId> {
+        let unlocked_at = unlocked_at.to_rfc3339_opts(SecondsFormat::Millis, true);
+        let keys: Vec<&str> = achievement_ids.iter().map(|achievement_id| achievement_id.key()).collect();
+        // $min keeps the first unlock time when the same achievement is unlocked again.
+        let mut first_unlocks = Document::new();
+        for key in &keys {
+            first_unlocks.insert(format!("unlocked_at.{}", key), unlocked_at.clone());
+        }
+        let update = doc! {
+            "$addToSet": { "achievements": { "$each": keys } },
+            "$min": first_unlocks,
+        };
+        let options = FindOneAndUpdateOptions::builder()
+            .upsert(true)
+            .return_document(ReturnDocument::Before)
+            .build();
+        println!("MongoDB unlock achievements: {}", id);
+        self.collection.find_one_and_update(doc! { "id": id }, update, options).await.unwrap()
+            .map(|previous| previous.achievements)
+            .unwrap_or_default()
+    }
+}
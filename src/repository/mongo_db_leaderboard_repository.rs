@@ -0,0 +1,189 @@
+use futures::TryStreamExt;
+use mongodb::bson::{doc, Document};
+use mongodb::{Client, IndexModel};
+use mongodb::options::{ClientOptions, ReplaceOptions};
+use serde::Deserialize;
+
+use crate::model::leaderboard::{LeaderboardArchive, LeaderboardEntry, LeaderboardKind};
+use crate::repository::leaderboard_repository::{LeaderboardRepository, StandingsWindow};
+
+pub struct MongoDBLeaderboardRepository {
+    matches: mongodb::Collection<Document>,
+    archives: mongodb::Collection<LeaderboardArchive>,
+}
+
+#[derive(Deserialize)]
+struct StandingRow {
+    #[serde(rename = "_id")]
+    user_id: String,
+    name: String,
+    value: i64,
+    word: Option<String>,
+    rank: i64,
+}
+
+#[derive(Deserialize)]
+struct CountRow {
+    total: i64,
+}
+
+impl MongoDBLeaderboardRepository {
+    pub async fn new() -> Result<Self, mongodb::error::Error> {
+        let client_options = ClientOptions::parse("mongodb://127.0.0.1:27017").await?;
+        let client = Client::with_options(client_options)?;
+        let db = client.database("spell-fight-database");
+        let matches = db.collection::<Document>("matches");
+        let created_at_index = IndexModel::builder().keys(doc! { "created_at": 1 }).build();
+        matches.create_index(created_at_index, None).await?;
+        let archives = db.collection::<LeaderboardArchive>("leaderboard_archives");
+        Ok(Self { matches, archives })
+    }
+}
+
+fn match_filter(window: StandingsWindow) -> Document {
+    let mut filter = doc! { "practice": { "$ne": true } };
+    let mut created_at = Document::new();
+    if let Some(since) = window.since {
+        created_at.insert("$gte", mongodb::bson::DateTime::from_millis(since.timestamp_millis()));
+    }
+    if let Some(until) = window.until {
+        created_at.insert("$lt", mongodb::bson::DateTime::from_millis(until.timestamp_millis()));
+    }
+    if !created_at.is_empty() {
+        filter.insert("created_at", created_at);
+    }
+    filter
+}
+
+/// Ranked standings, players with the same value share a rank.
+fn standings_pipeline(kind: LeaderboardKind, window: StandingsWindow) -> Vec<Document> {
+    let mut pipeline = vec![
+        doc! { "$match": match_filter(window) },
+        doc! { "$sort": { "created_at": 1 } },
+        doc! { "$unwind": "$players" },
+        doc! { "$match": { "players.bot": false } },
+    ];
+    pipeline.extend(group_stage(kind));
+    pipeline.push(doc! { "$setWindowFields": {
+        "sortBy": { "value": -1 },
+        "output": { "rank": { "$rank": {} } },
+    } });
+    pipeline.push(doc! { "$sort": { "value": -1, "_id": 1 } });
+    pipeline
+}
+
+impl From<StandingRow> for LeaderboardEntry {
+    fn from(row: StandingRow) -> Self {
+        LeaderboardEntry {
+            rank: usize::try_from(row.rank).unwrap_or(0),
+            user_id: row.user_id,
+            name: row.name,
+            value: row.value,
+            word: row.word,
+        }
+    }
+}
+
+impl MongoDBLeaderboardRepository {
+    async fn aggregate(&self, pipeline: Vec<Document>) -> Vec<Document> {
+        let cursor = self.matches.aggregate(pipeline, None).await.unwrap();
+        cursor.try_collect().await.unwrap()
+    }
+}
+
+fn group_stage(kind: LeaderboardKind) -> Vec<Document> {
+    match kind {
+        LeaderboardKind::Rating => vec![
+            doc! { "$match": { "players.rating": { "$ne": null } } },
+            doc! { "$group": {
+                "_id": "$players.user_id",
+                "name": { "$last": "$players.name" },
+                "value": { "$last": "$players.rating" },
+            } },
+        ],
+        LeaderboardKind::Wins => vec![
+            doc! { "$group": {
+                "_id": "$players.user_id",
+                "name": { "$last": "$players.name" },
+                "value": { "$sum": { "$cond": ["$players.won", 1, 0] } },
+            } },
+        ],
+        LeaderboardKind::Damage => vec![
+            doc! { "$group": {
+                "_id": "$players.user_id",
+                "name": { "$last": "$players.name" },
+                "value": { "$sum": "$players.damage_dealt" },
+            } },
+        ],
+        LeaderboardKind::LongestWord => vec![
+            doc! { "$match": { "players.longest_word_length": { "$gt": 0 } } },
+            doc! { "$sort": { "players.longest_word_length": -1, "created_at": 1 } },
+            doc! { "$group": {
+                "_id": "$players.user_id",
+                "name": { "$first": "$players.name" },
+                "value": { "$first": "$players.longest_word_length" },
+                "word": { "$first": "$players.longest_word" },
+            } },
+        ],
+    }
+}
+
+#[async_trait::async_trait]
+impl LeaderboardRepository for MongoDBLeaderboardRepository {
+    async fn find_standings(
+        &self,
+        kind: LeaderboardKind,
+        window: StandingsWindow,
+        skip: usize,
+        limit: Option<usize>,
+    ) -> Vec<LeaderboardEntry> {
+        let mut pipeline = standings_pipeline(kind, window);
+        pipeline.push(doc! { "$skip": i64::try_from(skip).unwrap_or(i64::MAX) });
+        if let Some(limit) = limit {
+            pipeline.push(doc! { "$limit": i64::try_from(limit).unwrap_or(i64::MAX) });
+        }
+
+        self.aggregate(pipeline).await.into_iter()
+            .filter_map(|document| mongodb::bson::from_document::<StandingRow>(document).ok())
+            .map(LeaderboardEntry::from)
+            .collect()
+    }
+
+    async fn count_standings(&self, kind: LeaderboardKind, window: StandingsWindow) -> usize {
+        let mut pipeline = vec![
+            doc! { "$match": match_filter(window) },
+            doc! { "$unwind": "$players" },
+            doc! { "$match": { "players.bot": false } },
+        ];
+        pipeline.extend(group_stage(kind));
+        pipeline.push(doc! { "$count": "total" });
+
+        self.aggregate(pipeline).await.into_iter()
+            .filter_map(|document| mongodb::bson::from_document::<CountRow>(document).ok())
+            .map(|row| usize::try_from(row.total).unwrap_or(0))
+            .next()
+            .unwrap_or(0)
+    }
+
+    async fn find_standing(&self, kind: LeaderboardKind, window: StandingsWindow, user_id: &str) -> Option<LeaderboardEntry> {
+        let mut pipeline = standings_pipeline(kind, window);
+        pipeline.push(doc! { "$match": { "_id": user_id } });
+
+        self.aggregate(pipeline).await.into_iter()
+            .filter_map(|document| mongodb::bson::from_document::<StandingRow>(document).ok())
+            .map(LeaderboardEntry::from)
+            .next()
+    }
+
+    async fn find_archive(&self, id: &str) -> Option<LeaderboardArchive> {
+        let filter = doc! { "id": id };
+        self.archives.find_one(filter, None).await.unwrap()
+    }
+
+    async fn save_archive(&self, archive: LeaderboardArchive) {
+        let filter = doc! { "id": archive.id.as_str() };
+        let options = ReplaceOptions::builder().upsert(true).build();
+        println!("MongoDB save leaderboard archive: {}", archive.id);
+        self.archives.replace_one(filter, archive, options).await.unwrap();
+    }
+}
@@ -0,0 +1,44 @@
+use mongodb::Client;
+use mongodb::options::{ClientOptions, ReplaceOptions};
+
+use crate::model::match_record::MatchRecord;
+use crate::repository::repository::Repository;
+
+pub struct MongoDBMatchRepository {
+    collection: mongodb::Collection<MatchRecord>,
+}
+
+impl MongoDBMatchRepository {
+    pub async fn new() -> Result<Self, mongodb::error::Error> {
+        let client_options = ClientOptions::parse("mongodb://127.0.0.1:27017").await?;
+        let client = Client::with_options(client_options)?;
+        let db = client.database("spell-fight-database");
+        let collection = db.collection::<MatchRecord>("matches");
+        Ok(Self { collection })
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<MatchRecord> for MongoDBMatchRepository {
+    async fn find_by_id(&self, id: &str) -> Option<MatchRecord> {
+        let filter = mongodb::bson::doc! { "id": id };
+        self.collection.find_one(filter, None).await.unwrap()
+    }
+
+    async fn save(&self, match_record: MatchRecord) {
+        match self.find_by_id(match_record.id.as_str()).await {
+            Some(_) => {}
+            None => {
+                println!("MongoDB save match: {}", match_record.id);
+                self.collection.insert_one(match_record, None).await.unwrap();
+            }
+        }
+    }
+
+    async fn update(&self, match_record: MatchRecord) {
+        let filter = mongodb::bson::doc! { "id": match_record.id.as_str() };
+        let options = ReplaceOptions::builder().upsert(true).build();
+        println!("MongoDB update match: {}", match_record.id);
+        self.collection.replace_one(filter, match_record, options).await.unwrap();
+    }
+}
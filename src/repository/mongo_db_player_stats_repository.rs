@@ -0,0 +1,72 @@
+use mongodb::bson::{doc, Document};
+use mongodb::Client;
+use mongodb::options::{ClientOptions, IndexOptions, UpdateOptions};
+use mongodb::IndexModel;
+
+use crate::model::match_record::MatchPlayerSummary;
+use crate::model::player_stats::PlayerStats;
+use crate::repository::player_stats_repository::PlayerStatsRepository;
+
+pub struct MongoDBPlayerStatsRepository {
+    collection: mongodb::Collection<PlayerStats>,
+}
+
+impl MongoDBPlayerStatsRepository {
+    pub async fn new() -> Result<Self, mongodb::error::Error> {
+        let client_options = ClientOptions::parse("mongodb://127.0.0.1:27017").await?;
+        let client = Client::with_options(client_options)?;
+        let db = client.database("spell-fight-database");
+        let collection = db.collection::<PlayerStats>("player_stats");
+        // Concurrent upserts for a new player must not create two documents.
+        let id_index = IndexModel::builder()
+            .keys(doc! { "id": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+        collection.create_index(id_index, None).await?;
+        Ok(Self { collection })
+    }
+}
+
+#[async_trait::async_trait]
+impl PlayerStatsRepository for MongoDBPlayerStatsRepository {
+    async fn find_by_id(&self, id: &str) -> Option<PlayerStats> {
+        let filter = doc! { "id": id };
+        self.collection.find_one(filter, None).await.unwrap()
+    }
+
+    async fn record_match(&self, summary: &MatchPlayerSummary) {
+        let filter = doc! { "id": summary.user_id.as_str() };
+        let options = UpdateOptions::builder().upsert(true).build();
+        println!("MongoDB update player stats: {}", summary.user_id);
+        self.collection.update_one(filter, record_match_update(summary), options).await.unwrap();
+    }
+}
+
+fn record_match_update(summary: &MatchPlayerSummary) -> Document {
+    let mut increments = doc! {
+        "games_played": 1_i64,
+        "wins": i64::from(summary.won),
+        "words_submitted": i64::from(summary.words_submitted),
+        "words_accepted": i64::from(summary.words_accepted),
+        "total_damage": i64::from(summary.damage_dealt),
+        "total_turn_millis": summary.turn_millis as i64,
+        "timed_turns": i64::from(summary.timed_turns),
+    };
+    if summary.place > 0 {
+        increments.insert(format!("placement_counts.{}", summary.place), 1_i64);
+    }
+    for (letter, count) in summary.letters_used.iter() {
+        increments.insert(format!("letter_counts.{}", letter), i64::from(*count));
+    }
+    let mut update = doc! { "$inc": increments };
+    if let Some(word_damage) = &summary.highest_damage_word {
+        // Documents compare field by field, so damage has to come first.
+        update.insert("$max", doc! {
+            "highest_damage_word": {
+                "damage": i64::from(word_damage.damage),
+                "word": word_damage.word.as_str(),
+            },
+        });
+    }
+    update
+}
@@ -0,0 +1,44 @@
+use mongodb::Client;
+use mongodb::options::{ClientOptions, ReplaceOptions};
+
+use crate::model::tournament::Tournament;
+use crate::repository::repository::Repository;
+
+pub struct MongoDBTournamentRepository {
+    collection: mongodb::Collection<Tournament>,
+}
+
+impl MongoDBTournamentRepository {
+    pub async fn new() -> Result<Self, mongodb::error::Error> {
+        let client_options = ClientOptions::parse("mongodb://127.0.0.1:27017").await?;
+        let client = Client::with_options(client_options)?;
+        let db = client.database("spell-fight-database");
+        let collection = db.collection::<Tournament>("tournaments");
+        Ok(Self { collection })
+    }
+}
+
+#[async_trait::async_trait]
+impl Repository<Tournament> for MongoDBTournamentRepository {
+    async fn find_by_id(&self, id: &str) -> Option<Tournament> {
+        let filter = mongodb::bson::doc! { "id": id };
+        self.collection.find_one(filter, None).await.unwrap()
+    }
+
+    async fn save(&self, tournament: Tournament) {
+        match self.find_by_id(tournament.id.as_str()).await {
+            Some(_) => {}
+            None => {
+                println!("MongoDB save tournament: {}", tournament.id);
+                self.collection.insert_one(tournament, None).await.unwrap();
+            }
+        }
+    }
+
+    async fn update(&self, tournament: Tournament) {
+        let filter = mongodb::bson::doc! { "id": tournament.id.as_str() };
+        let options = ReplaceOptions::builder().upsert(true).build();
+        println!("MongoDB update tournament: {}", tournament.id);
+        self.collection.replace_one(filter, tournament, options).await.unwrap();
+    }
+}
@@ -1,5 +1,5 @@
 use mongodb::Client;
-use mongodb::options::ClientOptions;
+use mongodb::options::{ClientOptions, ReplaceOptions};
 
 use crate::model::user::User;
 use crate::repository::repository::Repository;
@@ -34,4 +34,11 @@ impl Repository<User> for MongoDBUserRepository {
             }
         }
     }
+
+    async fn update(&self, user: User) {
+        let filter = mongodb::bson::doc! { "id": user.id.as_str() };
+        let options = ReplaceOptions::builder().upsert(true).build();
+        println!("MongoDB update: {:?}", user);
+        self.collection.replace_one(filter, user, options).await.unwrap();
+    }
 }
\ No newline at end of file
@@ -0,0 +1,8 @@
+use crate::model::match_record::MatchPlayerSummary;
+use crate::model::player_stats::PlayerStats;
+
+#[async_trait::async_trait]
+pub trait PlayerStatsRepository {
+    async fn find_by_id(&self, id: &str) -> Option<PlayerStats>;
+    async fn record_match(&self, summary: &MatchPlayerSummary);
+}
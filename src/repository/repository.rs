@@ -2,4 +2,5 @@
 pub trait Repository<T> {
     async fn find_by_id(&self, id: &str) -> Option<T>;
     async fn save(&self, entity: T);
+    async fn update(&self, entity: T);
 }
\ No newline at end of file
@@ -0,0 +1,30 @@
+use chrono::Utc;
+
+use crate::model::achievement::{AchievementId, Badge};
+use crate::repository::achievement_repository::AchievementRepository;
+
+pub struct AchievementService<T: AchievementRepository> {
+    achievement_repository: T,
+}
+
+impl<T: AchievementRepository> AchievementService<T> {
+    pub fn new(achievement_repository: T) -> Self {
+        Self { achievement_repository }
+    }
+
+    pub async fn get_badges(&self, user_id: &str) -> Vec<Badge> {
+        self.achievement_repository.find_by_id(user_id).await
+            .map(|user_achievements| user_achievements.badges())
+            .unwrap_or_default()
+    }
+
+    /// Saves all of a user's unlocks in one write and returns the badges that were not unlocked before.
+    pub async fn unlock(&self, user_id: &str, achievement_ids: &[AchievementId]) -> Vec<Badge> {
+        let unlocked_at = Utc::now();
+        let previous = self.achievement_repository.unlock(user_id, achievement_ids, unlocked_at).await;
+        achievement_ids.iter()
+            .filter(|achievement_id| !previous.contains(achievement_id))
+            .map(|&achievement_id| Badge::new(achievement_id, unlocked_at))
+            .collect()
+    }
+}
@@ -1,28 +1,51 @@
+use std::collections::HashMap;
+
+use crate::dictionary::dictionary::Dictionary;
+use crate::dictionary::dictionary_backend::DictionaryBackend;
+use crate::dictionary::dictionary_factory::DictionaryFactory;
+use crate::language::language_registry::{get_languages, LanguageDefinition};
+use crate::model::language::Language;
 use crate::service::env_service::EnvService;
 
-pub struct DictionaryService;
+const DICTIONARY_CONFIG_KEYS: [&str; 3] = ["dictionary_backend", "dictionary_uri", "dictionary_path"];
 
-impl DictionaryService {
-    pub fn new() -> Self {
-        Self {}
-    }
+pub struct DictionaryService {
+    dictionaries: HashMap<Language, Box<dyn Dictionary>>,
+}
 
-    pub async fn word_exists(&self, word: &str) -> Result<bool, Box<dyn std::error::Error>> {
+impl DictionaryService {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         let env_service = EnvService::new();
+        let mut dictionaries = HashMap::new();
+        for language_definition in get_languages() {
+            let is_default = language_definition.language == Language::default();
+            if !is_default && !is_configured(language_definition, &env_service) {
+                continue;
+            }
 
-        let dictionary_uri = env_service.env_data.get("dictionary_uri");
-        let dictionary_uri = match dictionary_uri {
-            Some(value) => { value }
-            None => { return Err("No dictionary_uri in .env".into()); }
-        }.as_str();
+            let backend_key = language_definition.config_key("dictionary_backend");
+            let backend = DictionaryBackend::from_config(env_service.env_data.get(&backend_key))?;
+            let dictionary = DictionaryFactory::create_dictionary(backend, language_definition, &env_service)?;
+            dictionaries.insert(language_definition.language, dictionary);
+        }
 
-        let client = reqwest::Client::new();
-        let url = format!("{}/{}", dictionary_uri, word);
-        let res = client
-            .get(url)
-            .send()
-            .await?;
+        Ok(Self { dictionaries })
+    }
+
+    pub fn languages(&self) -> Vec<Language> {
+        self.dictionaries.keys().copied().collect()
+    }
 
-        Ok(res.status() != reqwest::StatusCode::NOT_FOUND)
+    pub async fn word_exists(&self, language: Language, word: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        let dictionary = match self.dictionaries.get(&language) {
+            Some(dictionary) => dictionary,
+            None => { return Err(format!("No dictionary configured for {:?}", language).into()); }
+        };
+        dictionary.word_exists(word.to_lowercase().as_str()).await
     }
-}
\ No newline at end of file
+}
+
+fn is_configured(language_definition: &LanguageDefinition, env_service: &EnvService) -> bool {
+    DICTIONARY_CONFIG_KEYS.iter()
+        .any(|key| env_service.env_data.contains_key(&language_definition.config_key(key)))
+}
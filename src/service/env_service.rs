@@ -16,7 +16,7 @@ impl EnvService {
         let data = env_reader.read(ENV_PATH);
 
         Self {
-            env_data: data.unwrap_or(HashMap::new())
+            env_data: data.unwrap_or_default()
         }
     }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use crate::model::facebook_profile::FacebookProfile;
 use crate::model::user::User;
 use crate::service::env_service::EnvService;
+use crate::util::constants::DEFAULT_RATING;
 
 pub struct FacebookService;
 
@@ -71,6 +72,7 @@ impl User {
             email: profile.email.unwrap_or(String::from("")).clone(),
             photo: "".to_string(),
             provider: "".to_string(),
+            rating: DEFAULT_RATING,
         }
     }
 }
\ No newline at end of file
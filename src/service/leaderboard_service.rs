@@ -0,0 +1,124 @@
+use chrono::{DateTime, Datelike, Duration, TimeZone, Utc};
+
+use crate::model::leaderboard::{Leaderboard, LeaderboardArchive, LeaderboardKind, LeaderboardPeriod, LeaderboardQuery, Season};
+use crate::repository::leaderboard_repository::{LeaderboardRepository, StandingsWindow};
+use crate::service::env_service::EnvService;
+use crate::util::constants::{SEASON_EPOCH, SEASON_LENGTH_DAYS};
+
+pub struct LeaderboardService<T: LeaderboardRepository> {
+    leaderboard_repository: T,
+    season_epoch: DateTime<Utc>,
+    season_length: Duration,
+}
+
+impl<T: LeaderboardRepository> LeaderboardService<T> {
+    pub fn new(leaderboard_repository: T) -> Self {
+        let env_service = EnvService::new();
+        let season_epoch = env_service.env_data.get("season_start")
+            .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+            .or_else(|| DateTime::parse_from_rfc3339(SEASON_EPOCH).ok())
+            .map(|epoch| epoch.with_timezone(&Utc))
+            .unwrap_or_default();
+        let season_length_days = env_service.env_data.get("season_length_days")
+            .and_then(|value| value.parse().ok())
+            .filter(|&days: &i64| days > 0)
+            .unwrap_or(SEASON_LENGTH_DAYS);
+
+        Self {
+            leaderboard_repository,
+            season_epoch,
+            season_length: Duration::days(season_length_days),
+        }
+    }
+
+    pub fn current_season(&self) -> Season {
+        let elapsed = Utc::now() - self.season_epoch;
+        let number = (elapsed.num_seconds() / self.season_length.num_seconds()).max(0);
+        self.season(u32::try_from(number).unwrap_or(0))
+    }
+
+    fn season(&self, number: u32) -> Season {
+        let starts_at = self.season_epoch + self.season_length * i32::try_from(number).unwrap_or(i32::MAX);
+        Season {
+            number,
+            starts_at,
+            ends_at: starts_at + self.season_length,
+        }
+    }
+
+    pub async fn get_leaderboard(&self, kind: LeaderboardKind, query: &LeaderboardQuery, user_id: Option<&str>) -> Leaderboard {
+        let (since, season) = match query.period {
+            LeaderboardPeriod::AllTime => (None, None),
+            LeaderboardPeriod::Seasonal => {
+                let season = self.current_season();
+                if season.number > 0 {
+                    self.get_season_archive(kind, season.number - 1).await;
+                }
+                (Some(season.starts_at), Some(season.number))
+            }
+            LeaderboardPeriod::Weekly => (Some(start_of_week(Utc::now())), None),
+        };
+
+        let window = StandingsWindow { since, until: None };
+        let page = query.page();
+        let page_size = query.page_size();
+        let entries = self.leaderboard_repository.find_standings(kind, window, (page - 1) * page_size, Some(page_size)).await;
+        let total = self.leaderboard_repository.count_standings(kind, window).await;
+        let me = match user_id {
+            Some(user_id) => self.leaderboard_repository.find_standing(kind, window, user_id).await,
+            None => None,
+        };
+        Leaderboard {
+            kind,
+            period: query.period,
+            season,
+            page,
+            page_size,
+            total,
+            entries,
+            me,
+        }
+    }
+
+    /// Archives are written the first time a finished season is requested.
+    pub async fn get_season_archive(&self, kind: LeaderboardKind, season: u32) -> Option<LeaderboardArchive> {
+        if season >= self.current_season().number {
+            return None;
+        }
+        let id = format!("season-{}-{}", season, kind_name(kind));
+        if let Some(archive) = self.leaderboard_repository.find_archive(&id).await {
+            return Some(archive);
+        }
+
+        let season = self.season(season);
+        let window = StandingsWindow {
+            since: Some(season.starts_at),
+            until: Some(season.ends_at),
+        };
+        let archive = LeaderboardArchive {
+            id,
+            kind,
+            season: season.number,
+            starts_at: season.starts_at,
+            ends_at: season.ends_at,
+            entries: self.leaderboard_repository.find_standings(kind, window, 0, None).await,
+        };
+        self.leaderboard_repository.save_archive(archive.clone()).await;
+        Some(archive)
+    }
+}
+
+fn start_of_week(now: DateTime<Utc>) -> DateTime<Utc> {
+    let days_since_monday = i64::from(now.weekday().num_days_from_monday());
+    let monday = now.date_naive() - Duration::days(days_since_monday);
+    Utc.from_utc_datetime(&monday.and_hms_opt(0, 0, 0).unwrap_or_default())
+}
+
+fn kind_name(kind: LeaderboardKind) -> &'static str {
+    match kind {
+        LeaderboardKind::Rating => "rating",
+        LeaderboardKind::Wins => "wins",
+        LeaderboardKind::Damage => "damage",
+        LeaderboardKind::LongestWord => "longest_word",
+    }
+}
@@ -0,0 +1,20 @@
+use crate::model::match_record::MatchRecord;
+use crate::repository::repository::Repository;
+
+pub struct MatchService<T: Repository<MatchRecord>> {
+    match_repository: T,
+}
+
+impl<T: Repository<MatchRecord>> MatchService<T> {
+    pub fn new(match_repository: T) -> Self {
+        Self { match_repository }
+    }
+
+    pub async fn get_match(&self, id: &str) -> Option<MatchRecord> {
+        self.match_repository.find_by_id(id).await
+    }
+
+    pub async fn save_match(&self, match_record: MatchRecord) {
+        self.match_repository.save(match_record).await;
+    }
+}
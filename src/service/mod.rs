@@ -1,4 +1,9 @@
 pub mod user_service;
 pub mod facebook_service;
 pub mod env_service;
-pub mod dictionary_service;
\ No newline at end of file
+pub mod dictionary_service;
+pub mod match_service;
+pub mod tournament_service;
+pub mod leaderboard_service;
+pub mod player_stats_service;
+pub mod achievement_service;
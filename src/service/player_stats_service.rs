@@ -0,0 +1,24 @@
+use crate::model::match_record::MatchRecord;
+use crate::model::player_stats::PlayerStats;
+use crate::repository::player_stats_repository::PlayerStatsRepository;
+
+pub struct PlayerStatsService<T: PlayerStatsRepository> {
+    player_stats_repository: T,
+}
+
+impl<T: PlayerStatsRepository> PlayerStatsService<T> {
+    pub fn new(player_stats_repository: T) -> Self {
+        Self { player_stats_repository }
+    }
+
+    pub async fn get_player_stats(&self, user_id: &str) -> Option<PlayerStats> {
+        self.player_stats_repository.find_by_id(user_id).await
+            .map(PlayerStats::with_derived_stats)
+    }
+
+    pub async fn record_match(&self, match_record: &MatchRecord) {
+        for summary in match_record.players.iter().filter(|summary| !summary.bot) {
+            self.player_stats_repository.record_match(summary).await;
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::model::tournament::Tournament;
+use crate::repository::repository::Repository;
+
+pub struct TournamentService<T: Repository<Tournament>> {
+    tournament_repository: T,
+}
+
+impl<T: Repository<Tournament>> TournamentService<T> {
+    pub fn new(tournament_repository: T) -> Self {
+        Self { tournament_repository }
+    }
+
+    pub async fn get_tournament(&self, id: &str) -> Option<Tournament> {
+        self.tournament_repository.find_by_id(id).await
+    }
+
+    pub async fn save_tournament(&self, tournament: Tournament) {
+        self.tournament_repository.update(tournament).await;
+    }
+}
@@ -17,4 +17,8 @@ impl<T: Repository<User>> UserService<T> {
     pub async fn create_user(&self, user: User) {
         self.user_repository.save(user).await;
     }
+
+    pub async fn update_user(&self, user: User) {
+        self.user_repository.update(user).await;
+    }
 }
\ No newline at end of file
@@ -0,0 +1,290 @@
+use crate::model::tournament::{MatchSlot, TournamentBracket, TournamentFormat, TournamentMatch, TournamentMatchStatus};
+use crate::model::user::User;
+
+/// Builds the full bracket up front. Players are expected to be sorted by seed,
+/// missing seeds become byes that are resolved by `advance`.
+pub fn create_bracket(players: &[User], format: TournamentFormat) -> Vec<TournamentMatch> {
+    let bracket_size = players.len().next_power_of_two().max(2);
+    let rounds = bracket_size.trailing_zeros() as usize;
+    let mut matches = Vec::new();
+
+    let winners_rounds: Vec<Vec<usize>> = (1..=rounds)
+        .map(|round| add_round(&mut matches, TournamentBracket::Winners, round, bracket_size >> round))
+        .collect();
+    for round in 1..rounds {
+        for (index, &match_id) in winners_rounds[round - 1].iter().enumerate() {
+            matches[match_id].winner_to = Some(slot_of(&winners_rounds[round], index / 2, index % 2));
+        }
+    }
+
+    for (position, seed) in seeding_order(bracket_size).into_iter().enumerate() {
+        let first_round_match = &mut matches[winners_rounds[0][position / 2]];
+        first_round_match.players[position % 2] = players.get(seed - 1).cloned();
+        first_round_match.resolved[position % 2] = true;
+    }
+
+    if format == TournamentFormat::DoubleElimination {
+        add_losers_bracket(&mut matches, &winners_rounds);
+    }
+    matches
+}
+
+/// Grand final winner takes the tournament, there is no bracket reset.
+fn add_losers_bracket(matches: &mut Vec<TournamentMatch>, winners_rounds: &[Vec<usize>]) {
+    let rounds = winners_rounds.len();
+    let winners_final = winners_rounds[rounds - 1][0];
+    let grand_final = add_round(matches, TournamentBracket::GrandFinal, 1, 1)[0];
+    matches[winners_final].winner_to = Some(MatchSlot { match_id: grand_final, slot: 0 });
+    if rounds == 1 {
+        matches[winners_final].loser_to = Some(MatchSlot { match_id: grand_final, slot: 1 });
+        return;
+    }
+
+    let mut previous_round: Vec<usize> = Vec::new();
+    for losers_round in 1..=2 * (rounds - 1) {
+        let round = if losers_round == 1 {
+            let round = add_round(matches, TournamentBracket::Losers, losers_round, winners_rounds[0].len() / 2);
+            for (index, &match_id) in winners_rounds[0].iter().enumerate() {
+                matches[match_id].loser_to = Some(slot_of(&round, index / 2, index % 2));
+            }
+            round
+        } else if losers_round % 2 == 0 {
+            let round = add_round(matches, TournamentBracket::Losers, losers_round, previous_round.len());
+            for (index, &match_id) in previous_round.iter().enumerate() {
+                matches[match_id].winner_to = Some(slot_of(&round, index, 0));
+            }
+            for (index, &match_id) in winners_rounds[losers_round / 2].iter().enumerate() {
+                matches[match_id].loser_to = Some(slot_of(&round, index, 1));
+            }
+            round
+        } else {
+            let round = add_round(matches, TournamentBracket::Losers, losers_round, previous_round.len() / 2);
+            for (index, &match_id) in previous_round.iter().enumerate() {
+                matches[match_id].winner_to = Some(slot_of(&round, index / 2, index % 2));
+            }
+            round
+        };
+        previous_round = round;
+    }
+    matches[previous_round[0]].winner_to = Some(MatchSlot { match_id: grand_final, slot: 1 });
+}
+
+fn add_round(matches: &mut Vec<TournamentMatch>, bracket: TournamentBracket, round: usize, count: usize) -> Vec<usize> {
+    (0..count)
+        .map(|_| {
+            let id = matches.len();
+            matches.push(TournamentMatch {
+                id,
+                bracket,
+                round,
+                players: [None, None],
+                resolved: [false, false],
+                winner_to: None,
+                loser_to: None,
+                status: TournamentMatchStatus::Pending,
+                room_code: None,
+                winner: None,
+            });
+            id
+        })
+        .collect()
+}
+
+fn slot_of(round: &[usize], index: usize, slot: usize) -> MatchSlot {
+    MatchSlot {
+        match_id: round[index],
+        slot,
+    }
+}
+
+fn seeding_order(bracket_size: usize) -> Vec<usize> {
+    let mut order = vec![1];
+    while order.len() < bracket_size {
+        let size = order.len() * 2;
+        order = order.into_iter()
+            .flat_map(|seed| [seed, size + 1 - seed])
+            .collect();
+    }
+    order
+}
+
+/// Resolves byes and returns the ids of matches that now have both players.
+pub fn advance(matches: &mut [TournamentMatch]) -> Vec<usize> {
+    let mut ready_matches = Vec::new();
+    loop {
+        let resolvable = matches.iter()
+            .find(|tournament_match| {
+                tournament_match.status == TournamentMatchStatus::Pending
+                    && tournament_match.resolved.iter().all(|&resolved| resolved)
+            })
+            .map(|tournament_match| tournament_match.id);
+        let match_id = match resolvable {
+            Some(match_id) => match_id,
+            None => return ready_matches,
+        };
+
+        let players: Vec<User> = matches[match_id].players.iter().flatten().cloned().collect();
+        if players.len() == 2 {
+            matches[match_id].status = TournamentMatchStatus::Ready;
+            ready_matches.push(match_id);
+        } else {
+            finish_match(matches, match_id, players.into_iter().next());
+        }
+    }
+}
+
+/// Records the result of a played match. Falls back to the higher seed when the
+/// room finished without a winner, `seeds` is the player list sorted by seed.
+pub fn report_result(matches: &mut [TournamentMatch], seeds: &[User], match_id: usize, winner_id: Option<&str>) -> Vec<usize> {
+    let tournament_match = match matches.get(match_id) {
+        Some(tournament_match) if tournament_match.status == TournamentMatchStatus::InProgress => tournament_match,
+        _ => return Vec::new(),
+    };
+    let seed_of = |player: &User| seeds.iter().position(|seeded| seeded.id == player.id).unwrap_or(usize::MAX);
+    let winner = tournament_match.players.iter().flatten()
+        .find(|player| Some(player.id.as_str()) == winner_id)
+        .or_else(|| tournament_match.players.iter().flatten().min_by_key(|player| seed_of(player)))
+        .cloned();
+    finish_match(matches, match_id, winner);
+    advance(matches)
+}
+
+pub fn champion(matches: &[TournamentMatch]) -> Option<Option<User>> {
+    matches.iter()
+        .find(|tournament_match| tournament_match.winner_to.is_none())
+        .filter(|tournament_match| tournament_match.status == TournamentMatchStatus::Finished)
+        .map(|tournament_match| tournament_match.winner.clone())
+}
+
+fn finish_match(matches: &mut [TournamentMatch], match_id: usize, winner: Option<User>) {
+    let tournament_match = &mut matches[match_id];
+    tournament_match.status = TournamentMatchStatus::Finished;
+    tournament_match.winner = winner.clone();
+    let loser = tournament_match.players.iter().flatten()
+        .find(|player| Some(&player.id) != winner.as_ref().map(|winner| &winner.id))
+        .cloned();
+    let winner_to = tournament_match.winner_to;
+    let loser_to = tournament_match.loser_to;
+
+    if let Some(winner_to) = winner_to {
+        fill_slot(matches, winner_to, winner);
+    }
+    if let Some(loser_to) = loser_to {
+        fill_slot(matches, loser_to, loser);
+    }
+}
+
+fn fill_slot(matches: &mut [TournamentMatch], match_slot: MatchSlot, player: Option<User>) {
+    let tournament_match = &mut matches[match_slot.match_id];
+    tournament_match.players[match_slot.slot] = player;
+    tournament_match.resolved[match_slot.slot] = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded_players(count: usize) -> Vec<User> {
+        (1..=count)
+            .map(|seed| User {
+                id: format!("seed-{}", seed),
+                name: format!("Seed {}", seed),
+                email: String::new(),
+                photo: String::new(),
+                provider: String::new(),
+                rating: 2000 - i32::try_from(seed).unwrap(),
+            })
+            .collect()
+    }
+
+    fn start_matches(matches: &mut [TournamentMatch], match_ids: &[usize]) {
+        for &match_id in match_ids {
+            matches[match_id].status = TournamentMatchStatus::InProgress;
+        }
+    }
+
+    /// Plays the bracket out with `pick_winner` and returns how many matches were played.
+    fn play_out<F>(matches: &mut [TournamentMatch], players: &[User], pick_winner: F) -> usize
+        where F: Fn(&[User]) -> Option<String>
+    {
+        let mut ready_matches = advance(matches);
+        let mut played = 0;
+        while let Some(match_id) = ready_matches.pop() {
+            start_matches(matches, &[match_id]);
+            let match_players: Vec<User> = matches[match_id].players.iter().flatten().cloned().collect();
+            let winner_id = pick_winner(&match_players);
+            ready_matches.extend(report_result(matches, players, match_id, winner_id.as_deref()));
+            played += 1;
+        }
+        played
+    }
+
+    fn top_seed_wins(match_players: &[User]) -> Option<String> {
+        match_players.iter().max_by_key(|player| player.rating).map(|player| player.id.clone())
+    }
+
+    #[test]
+    fn single_elimination_plays_one_match_per_eliminated_player() {
+        for count in 2..=17 {
+            let players = seeded_players(count);
+            let mut matches = create_bracket(&players, TournamentFormat::SingleElimination);
+            let played = play_out(&mut matches, &players, top_seed_wins);
+            assert_eq!(played, count - 1, "{} players", count);
+            assert_eq!(champion(&matches), Some(Some(players[0].clone())));
+        }
+    }
+
+    #[test]
+    fn double_elimination_eliminates_everyone_but_the_champion_twice() {
+        for count in 2..=17 {
+            let players = seeded_players(count);
+            let mut matches = create_bracket(&players, TournamentFormat::DoubleElimination);
+            let played = play_out(&mut matches, &players, top_seed_wins);
+            assert_eq!(played, 2 * count - 2, "{} players", count);
+            assert_eq!(champion(&matches), Some(Some(players[0].clone())));
+        }
+    }
+
+    #[test]
+    fn double_elimination_lets_a_losers_bracket_player_win() {
+        let players = seeded_players(4);
+        let mut matches = create_bracket(&players, TournamentFormat::DoubleElimination);
+        let played = play_out(&mut matches, &players, |match_players| {
+            let upset = match_players.iter().find(|player| player.id == "seed-2");
+            upset.or_else(|| match_players.iter().max_by_key(|player| player.rating))
+                .map(|player| player.id.clone())
+        });
+        assert_eq!(played, 6);
+        assert_eq!(champion(&matches), Some(Some(players[1].clone())));
+    }
+
+    #[test]
+    fn top_seeds_get_byes() {
+        let players = seeded_players(5);
+        let mut matches = create_bracket(&players, TournamentFormat::SingleElimination);
+        let ready_matches = advance(&mut matches);
+        let pairings: Vec<(usize, Vec<&str>)> = ready_matches.iter()
+            .map(|&match_id| {
+                let match_players = matches[match_id].players.iter().flatten()
+                    .map(|player| player.id.as_str())
+                    .collect();
+                (matches[match_id].round, match_players)
+            })
+            .collect();
+        assert_eq!(pairings, vec![(1, vec!["seed-4", "seed-5"]), (2, vec!["seed-2", "seed-3"])]);
+    }
+
+    #[test]
+    fn missing_winner_falls_back_to_the_higher_seed() {
+        let players = seeded_players(4);
+        let mut matches = create_bracket(&players, TournamentFormat::SingleElimination);
+        let ready_matches = advance(&mut matches);
+        start_matches(&mut matches, &ready_matches);
+        let (first_round, second_round) = (ready_matches[0], ready_matches[1]);
+        report_result(&mut matches, &players, first_round, Some("seed-4"));
+        let final_match = report_result(&mut matches, &players, second_round, Some("seed-3"));
+        start_matches(&mut matches, &final_match);
+        report_result(&mut matches, &players, final_match[0], None);
+        assert_eq!(champion(&matches), Some(Some(players[2].clone())));
+    }
+}
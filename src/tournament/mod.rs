@@ -0,0 +1,2 @@
+pub mod bracket;
+pub mod tournament_manager;
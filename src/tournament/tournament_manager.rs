@@ -0,0 +1,256 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use actix::{Actor, ActorFutureExt, Addr, AsyncContext, Context, Handler, MessageResult, WrapFuture};
+use actix_web::web::Data;
+use chrono::Utc;
+
+use crate::model::player_session_messages::{TournamentMatchReady, TournamentUpdated};
+use crate::model::room_manager_messages::CreateTournamentRoom;
+use crate::model::room_rules::RoomRules;
+use crate::model::tournament::{Tournament, TournamentError, TournamentMatchStatus, TournamentStatus};
+use crate::model::tournament_manager_messages::{CreateTournament, GetTournament, RegisterForTournament, StartTournament, SubscribeTournaments, TournamentMatchFinished, UnsubscribeTournaments};
+use crate::model::user::User;
+use crate::repository::mongo_db_tournament_repository::MongoDBTournamentRepository;
+use crate::service::env_service::EnvService;
+use crate::service::tournament_service::TournamentService;
+use crate::tournament::bracket::{advance, champion, create_bracket, report_result};
+use crate::util::constants::{TOURNAMENT_MAX_PLAYERS, TOURNAMENT_MIN_PLAYERS};
+use crate::ws::player_session::PlayerSession;
+use crate::ws::room_manager::RoomManager;
+
+pub struct TournamentManager {
+    tournaments: Vec<Tournament>,
+    subscribers: HashMap<String, Addr<PlayerSession>>,
+    admin_ids: Vec<String>,
+    room_manager: Addr<RoomManager>,
+    tournament_service: Data<TournamentService<MongoDBTournamentRepository>>,
+}
+
+impl TournamentManager {
+    pub fn new(
+        room_manager: Addr<RoomManager>,
+        tournament_service: Data<TournamentService<MongoDBTournamentRepository>>,
+    ) -> TournamentManager {
+        let env_service = EnvService::new();
+        let admin_ids = env_service.env_data.get("tournament_admin_ids")
+            .map(|ids| ids.split(',').map(|id| id.trim().to_string()).collect())
+            .unwrap_or_default();
+
+        TournamentManager {
+            tournaments: Vec::new(),
+            subscribers: HashMap::new(),
+            admin_ids,
+            room_manager,
+            tournament_service,
+        }
+    }
+
+    fn is_admin(&self, user: &User) -> bool {
+        self.admin_ids.contains(&user.id)
+    }
+
+    fn find_tournament(&mut self, tournament_id: &str) -> Option<&mut Tournament> {
+        self.tournaments.iter_mut().find(|tournament| tournament.id == tournament_id)
+    }
+
+    fn publish(&self, tournament: &Tournament) {
+        for player in tournament.players.iter() {
+            if let Some(session_addr) = self.subscribers.get(&player.id) {
+                session_addr.do_send(TournamentUpdated {
+                    tournament: tournament.clone(),
+                });
+            }
+        }
+
+        let tournament = tournament.clone();
+        let tournament_service = self.tournament_service.clone();
+        actix::spawn(async move {
+            tournament_service.save_tournament(tournament).await;
+        });
+    }
+
+    fn create_rooms(&mut self, tournament_id: &str, match_ids: Vec<usize>, ctx: &mut Context<Self>) {
+        let tournament = match self.tournaments.iter().find(|tournament| tournament.id == tournament_id) {
+            Some(tournament) => tournament,
+            None => return,
+        };
+
+        for match_id in match_ids {
+            let users: Vec<User> = tournament.matches[match_id].players.iter().flatten().cloned().collect();
+            let create_room = CreateTournamentRoom {
+                tournament_id: tournament.id.clone(),
+                match_id,
+                users: users.clone(),
+                rules: tournament.rules.clone(),
+                result_recipient: ctx.address().recipient(),
+            };
+            let tournament_id = tournament.id.clone();
+            let room_created = self.room_manager.send(create_room)
+                .into_actor(self)
+                .map(move |code, manager, _ctx| {
+                    if let Ok(code) = code {
+                        manager.on_room_created(&tournament_id, match_id, users, code);
+                    }
+                });
+            ctx.spawn(room_created);
+        }
+    }
+
+    fn on_room_created(&mut self, tournament_id: &str, match_id: usize, users: Vec<User>, code: String) {
+        let tournament = match self.find_tournament(tournament_id) {
+            Some(tournament) => tournament,
+            None => return,
+        };
+        let tournament_match = &mut tournament.matches[match_id];
+        tournament_match.status = TournamentMatchStatus::InProgress;
+        tournament_match.room_code = Some(code.clone());
+        let tournament = tournament.clone();
+
+        for (index, user) in users.iter().enumerate() {
+            if let Some(session_addr) = self.subscribers.get(&user.id) {
+                session_addr.do_send(TournamentMatchReady {
+                    tournament_id: tournament.id.clone(),
+                    match_id,
+                    code: code.clone(),
+                    opponent: users[1 - index].clone(),
+                });
+            }
+        }
+        self.publish(&tournament);
+    }
+}
+
+impl Actor for TournamentManager {
+    type Context = Context<Self>;
+}
+
+impl Handler<CreateTournament> for TournamentManager {
+    type Result = Result<Tournament, TournamentError>;
+
+    fn handle(&mut self, msg: CreateTournament, _ctx: &mut Context<Self>) -> Self::Result {
+        if !self.is_admin(&msg.user) {
+            return Err(TournamentError::Forbidden);
+        }
+        let request = msg.request;
+        let rules = RoomRules {
+            max_players: 2,
+            ..RoomRules::from_choice(request.rules)
+        };
+        let is_valid = !request.name.trim().is_empty()
+            && (TOURNAMENT_MIN_PLAYERS..=TOURNAMENT_MAX_PLAYERS).contains(&request.max_players)
+            && rules.is_valid();
+        if !is_valid {
+            return Err(TournamentError::InvalidSettings);
+        }
+
+        let tournament = Tournament {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: request.name.trim().to_string(),
+            format: request.format,
+            max_players: request.max_players,
+            rules,
+            status: TournamentStatus::Registration,
+            players: Vec::new(),
+            matches: Vec::new(),
+            winner: None,
+            created_at: Utc::now(),
+        };
+        self.publish(&tournament);
+        self.tournaments.push(tournament.clone());
+        Ok(tournament)
+    }
+}
+
+impl Handler<RegisterForTournament> for TournamentManager {
+    type Result = Result<Tournament, TournamentError>;
+
+    fn handle(&mut self, msg: RegisterForTournament, _ctx: &mut Context<Self>) -> Self::Result {
+        let tournament = self.find_tournament(&msg.tournament_id).ok_or(TournamentError::NotFound)?;
+        if tournament.status != TournamentStatus::Registration {
+            return Err(TournamentError::RegistrationClosed);
+        }
+        if tournament.is_participant(&msg.user.id) {
+            return Err(TournamentError::AlreadyRegistered);
+        }
+        if tournament.players.len() >= tournament.max_players {
+            return Err(TournamentError::TournamentFull);
+        }
+        tournament.players.push(msg.user);
+        let tournament = tournament.clone();
+        self.publish(&tournament);
+        Ok(tournament)
+    }
+}
+
+impl Handler<StartTournament> for TournamentManager {
+    type Result = Result<Tournament, TournamentError>;
+
+    fn handle(&mut self, msg: StartTournament, ctx: &mut Context<Self>) -> Self::Result {
+        if !self.is_admin(&msg.user) {
+            return Err(TournamentError::Forbidden);
+        }
+        let tournament = self.find_tournament(&msg.tournament_id).ok_or(TournamentError::NotFound)?;
+        if tournament.status != TournamentStatus::Registration {
+            return Err(TournamentError::RegistrationClosed);
+        }
+        if tournament.players.len() < TOURNAMENT_MIN_PLAYERS {
+            return Err(TournamentError::NotEnoughPlayers);
+        }
+
+        tournament.players.sort_by_key(|player| Reverse(player.rating));
+        tournament.matches = create_bracket(&tournament.players, tournament.format);
+        tournament.status = TournamentStatus::InProgress;
+        let ready_matches = advance(&mut tournament.matches);
+        let tournament = tournament.clone();
+        self.publish(&tournament);
+        self.create_rooms(&tournament.id, ready_matches, ctx);
+        Ok(tournament)
+    }
+}
+
+impl Handler<GetTournament> for TournamentManager {
+    type Result = MessageResult<GetTournament>;
+
+    fn handle(&mut self, msg: GetTournament, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.find_tournament(&msg.tournament_id).cloned())
+    }
+}
+
+impl Handler<TournamentMatchFinished> for TournamentManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: TournamentMatchFinished, ctx: &mut Context<Self>) {
+        let tournament = match self.find_tournament(&msg.tournament_id) {
+            Some(tournament) => tournament,
+            None => return,
+        };
+        let winner_id = msg.winner.as_ref().map(|winner| winner.id.as_str());
+        let ready_matches = report_result(&mut tournament.matches, &tournament.players, msg.match_id, winner_id);
+        if let Some(winner) = champion(&tournament.matches) {
+            tournament.status = TournamentStatus::Finished;
+            tournament.winner = winner;
+        }
+        let tournament = tournament.clone();
+        self.publish(&tournament);
+        self.create_rooms(&tournament.id, ready_matches, ctx);
+    }
+}
+
+impl Handler<SubscribeTournaments> for TournamentManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: SubscribeTournaments, _ctx: &mut Context<Self>) {
+        self.subscribers.insert(msg.user.id, msg.session_addr);
+    }
+}
+
+impl Handler<UnsubscribeTournaments> for TournamentManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: UnsubscribeTournaments, _ctx: &mut Context<Self>) {
+        if self.subscribers.get(&msg.user.id) == Some(&msg.session_addr) {
+            self.subscribers.remove(&msg.user.id);
+        }
+    }
+}
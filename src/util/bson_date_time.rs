@@ -0,0 +1,26 @@
+use chrono::{DateTime, TimeZone, Utc};
+use mongodb::bson::{self, Bson};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// MongoDB gets a BSON date it can index and compare, JSON clients keep getting an RFC 3339 string.
+pub fn serialize<S: Serializer>(date_time: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        date_time.serialize(serializer)
+    } else {
+        bson::DateTime::from_millis(date_time.timestamp_millis()).serialize(serializer)
+    }
+}
+
+/// Also accepts the RFC 3339 strings older documents were stored with.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+    match Bson::deserialize(deserializer)? {
+        Bson::DateTime(date_time) => Utc.timestamp_millis_opt(date_time.timestamp_millis())
+            .single()
+            .ok_or_else(|| D::Error::custom("date out of range")),
+        Bson::String(text) => DateTime::parse_from_rfc3339(&text)
+            .map(|date_time| date_time.with_timezone(&Utc))
+            .map_err(D::Error::custom),
+        _ => Err(D::Error::custom("expecting a date")),
+    }
+}
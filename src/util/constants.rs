@@ -1,5 +1,43 @@
-pub const MAX_PLAYERS_PER_ROOM: usize = 4;
-pub const MAX_LETTERS: usize = 14;
-pub const PREPARATION_TIME_SECONDS: u64 = 20;
-pub const TURN_SECONDS: u64 = 20;
-pub const ROLL_DICE_SECONDS: u64 = 20;
+pub const DISCONNECT_GRACE_SECONDS: u64 = 30;
+pub const MIN_PLAYERS_PER_ROOM: usize = 2;
+pub const ROOM_CODE_LENGTH: usize = 6;
+pub const DEFAULT_RATING: i32 = 1200;
+pub const RATING_K_FACTOR: f64 = 32.0;
+pub const RANKED_RATING_WINDOW: i32 = 100;
+pub const RANKED_RATING_WINDOW_GROWTH_PER_SECOND: i32 = 10;
+pub const MATCHMAKING_INTERVAL_SECONDS: u64 = 1;
+pub const CHAT_MAX_LENGTH: usize = 200;
+pub const CHAT_RATE_LIMIT_MESSAGES: usize = 5;
+pub const CHAT_RATE_LIMIT_SECONDS: u64 = 10;
+pub const LOBBY_COUNTDOWN_SECONDS: u64 = 5;
+pub const READY_CHECK_TIMEOUT_SECONDS: u64 = 30;
+pub const BOT_FILL_SECONDS: u64 = 30;
+pub const BOT_MIN_THINKING_SECONDS: u64 = 2;
+pub const BOT_MAX_THINKING_SECONDS: u64 = 6;
+pub const DICTIONARY_TIMEOUT_MILLISECONDS: u64 = 2000;
+pub const DICTIONARY_MAX_RETRIES: u32 = 2;
+pub const DICTIONARY_RETRY_BACKOFF_MILLISECONDS: u64 = 100;
+pub const DICTIONARY_CIRCUIT_FAILURE_THRESHOLD: u32 = 5;
+pub const DICTIONARY_CIRCUIT_COOLDOWN_SECONDS: u64 = 30;
+pub const DICTIONARY_CACHE_CAPACITY: usize = 10000;
+pub const DICTIONARY_CACHE_TTL_SECONDS: u64 = 3600;
+pub const HEAL_AMOUNT: u32 = 10;
+pub const SHIELD_AMOUNT: u32 = 15;
+pub const POISON_DAMAGE: u32 = 5;
+pub const POISON_TURNS: u32 = 3;
+pub const SHIELD_TURNS: u32 = 3;
+pub const FREEZE_TURNS: u32 = 1;
+pub const REGENERATION_AMOUNT: u32 = 5;
+pub const REGENERATION_TURNS: u32 = 3;
+pub const SILENCE_TURNS: u32 = 1;
+pub const SILENCE_MIN_LETTER_VALUE: u32 = 5;
+pub const TEAMS_COUNT: usize = 2;
+pub const TEAM_MODE_PLAYERS: usize = 4;
+pub const TOURNAMENT_MIN_PLAYERS: usize = 2;
+pub const TOURNAMENT_MAX_PLAYERS: usize = 64;
+pub const TOURNAMENT_JOIN_SECONDS: u64 = 300;
+pub const LEADERBOARD_DEFAULT_PAGE_SIZE: usize = 20;
+pub const LEADERBOARD_MAX_PAGE_SIZE: usize = 100;
+pub const SEASON_LENGTH_DAYS: i64 = 28;
+pub const SEASON_EPOCH: &str = "2024-01-01T00:00:00Z";
+pub const FAVOURITE_LETTERS_COUNT: usize = 5;
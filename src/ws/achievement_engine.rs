@@ -0,0 +1,79 @@
+use crate::model::achievement::{AchievementCondition, AchievementId, ACHIEVEMENTS};
+use crate::model::player_session_messages::GameOver;
+use crate::model::ws_response::WsResponse;
+
+#[derive(Debug, Clone, Default)]
+pub struct AchievementEngine {
+    damage_taken: Vec<u32>,
+    unlocked: Vec<Vec<AchievementId>>,
+    pending: Vec<(usize, AchievementId)>,
+}
+
+impl AchievementEngine {
+    pub fn new(players_count: usize) -> Self {
+        Self {
+            damage_taken: vec![0; players_count],
+            unlocked: vec![Vec::new(); players_count],
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn on_event(&mut self, player_index: Option<usize>, event: &WsResponse) {
+        match event {
+            WsResponse::WordValidated(word_validated) if word_validated.valid => {
+                self.check(word_validated.player_index, |condition| condition.is_met_by_word(&word_validated.word));
+            }
+            WsResponse::DiceRolledResponse(dice_roll_result) => {
+                if let Some(player_index) = player_index {
+                    self.check(player_index, |condition| {
+                        matches!(condition, AchievementCondition::WordDamageAtLeast(damage) if dice_roll_result.damage >= *damage)
+                    });
+                }
+            }
+            WsResponse::DamagePlayer(damage_player) => {
+                if let Some(damage_taken) = self.damage_taken.get_mut(damage_player.player_index) {
+                    *damage_taken += damage_player.damage;
+                }
+            }
+            WsResponse::GameOver(game_over) => self.on_game_over(game_over),
+            _ => {}
+        }
+    }
+
+    pub fn take_unlocked(&mut self) -> Vec<(usize, AchievementId)> {
+        std::mem::take(&mut self.pending)
+    }
+
+    fn on_game_over(&mut self, game_over: &GameOver) {
+        for (player_index, player) in game_over.players.iter().enumerate() {
+            let won = match game_over.winning_team {
+                Some(winning_team) => player.team == Some(winning_team),
+                None => game_over.winner.as_ref().is_some_and(|winner| winner.id == player.user.id),
+            };
+            if !won {
+                continue;
+            }
+            let untouched = self.damage_taken.get(player_index) == Some(&0);
+            self.check(player_index, |condition| match condition {
+                AchievementCondition::WinMatch => true,
+                AchievementCondition::WinWithoutTakingDamage => untouched,
+                _ => false,
+            });
+        }
+    }
+
+    fn check<F>(&mut self, player_index: usize, is_met: F)
+        where F: Fn(&AchievementCondition) -> bool
+    {
+        let unlocked = match self.unlocked.get_mut(player_index) {
+            Some(unlocked) => unlocked,
+            None => return,
+        };
+        for definition in ACHIEVEMENTS.iter() {
+            if !unlocked.contains(&definition.id) && is_met(&definition.condition) {
+                unlocked.push(definition.id);
+                self.pending.push((player_index, definition.id));
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use std::time::Duration;
+
+use actix::{Actor, ActorContext, Addr, AsyncContext, Context, Handler};
+use rand::{Rng, SeedableRng};
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+use crate::model::bot_difficulty::BotDifficulty;
+use crate::model::letter::Letter;
+use crate::model::player_session_messages::{ChatMessage, CheckWordExisting, DamagePlayer, DiceRollResult, EmoteMessage, GameOver, HealPlayer, InvalidTarget, LettersSilenced, LobbyState, NextTurn, PlayerDead, PlayerLeft, PlayerRolledDice, ReadyCheckFailed, RoomClosed, SpellEffect, StartPreparationTime, StatusEffectsUpdated, TakeDamage, TeammateRack, TilesRemaining, WordCreated, WordValidated};
+use crate::model::room_manager_messages::{CreateWord, RoomNextTurn, RoomNextTurnTimeoutInit, RoomRollDice, RoomWordValidated};
+use crate::model::room_rules::RoomRules;
+use crate::model::user::User;
+use crate::util::constants::{BOT_MAX_THINKING_SECONDS, BOT_MIN_THINKING_SECONDS, DEFAULT_RATING};
+use crate::ws::letters::{get_word_value, player_has_letters_for_word};
+use crate::ws::room_manager::RoomManager;
+
+const BOT_WORDS: &str = include_str!("../../resources/bot_words.txt");
+const BOT_NAMES: [&str; 8] = ["Ada", "Basil", "Cleo", "Dorian", "Elsa", "Felix", "Greta", "Hugo"];
+
+pub struct BotSession {
+    pub player: User,
+    pub difficulty: BotDifficulty,
+    pub letters: Vec<Letter>,
+    pub rules: RoomRules,
+    pub player_index: Option<usize>,
+    pub players_count: usize,
+    pub room_manager: Addr<RoomManager>,
+    rng: ChaCha8Rng,
+}
+
+impl BotSession {
+    pub fn new(difficulty: BotDifficulty, room_manager: Addr<RoomManager>, seed: u64) -> BotSession {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        BotSession {
+            player: create_bot_user(&mut rng),
+            difficulty,
+            letters: Vec::new(),
+            rules: RoomRules::default(),
+            player_index: None,
+            players_count: 0,
+            room_manager,
+            rng,
+        }
+    }
+
+    fn choose_word(&mut self) -> Option<String> {
+        let mut candidates: Vec<&str> = BOT_WORDS.lines()
+            .filter(|word| player_has_letters_for_word(self.letters.clone(), word))
+            .collect();
+        if candidates.is_empty() {
+            return None;
+        }
+        candidates.sort_by_key(|word| get_word_value(self.rules.language, word.to_string()));
+
+        let word = match self.difficulty {
+            BotDifficulty::Easy => *candidates.choose(&mut self.rng)?,
+            BotDifficulty::Medium => candidates[candidates.len() / 2],
+            BotDifficulty::Hard => *candidates.last()?,
+        };
+        Some(word.to_string())
+    }
+
+    fn thinking_duration(&mut self) -> Duration {
+        let max_seconds = BOT_MAX_THINKING_SECONDS.min(self.rules.turn_seconds.saturating_sub(1));
+        let min_seconds = BOT_MIN_THINKING_SECONDS.min(max_seconds);
+        Duration::from_secs(self.rng.gen_range(min_seconds..=max_seconds))
+    }
+}
+
+pub fn create_bot_user(rng: &mut ChaCha8Rng) -> User {
+    let name = BOT_NAMES.choose(rng).unwrap_or(&"Bot");
+    User {
+        id: format!("bot-{}", uuid::Builder::from_random_bytes(rng.gen()).into_uuid()),
+        name: format!("{} (bot)", name),
+        email: String::new(),
+        photo: String::new(),
+        provider: "bot".to_string(),
+        rating: DEFAULT_RATING,
+    }
+}
+
+impl Actor for BotSession {
+    type Context = Context<Self>;
+}
+
+impl Handler<StartPreparationTime> for BotSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: StartPreparationTime, ctx: &mut Self::Context) {
+        self.letters = msg.letters;
+        self.rules = msg.rules;
+        self.players_count = msg.users.len();
+        self.player_index = msg.users.iter().position(|user| user.id == self.player.id);
+
+        ctx.run_later(Duration::from_secs(msg.seconds), |bot, ctx| {
+            ctx.address().do_send(NextTurn {
+                player_index: 0,
+                seconds: bot.rules.turn_seconds,
+            });
+        });
+    }
+}
+
+impl Handler<NextTurn> for BotSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: NextTurn, ctx: &mut Self::Context) {
+        if self.player_index != Some(msg.player_index) {
+            return;
+        }
+
+        self.room_manager.do_send(RoomNextTurnTimeoutInit {
+            user: self.player.clone()
+        });
+        ctx.run_later(self.thinking_duration(), |bot, ctx| {
+            match bot.choose_word() {
+                Some(word) => bot.room_manager.do_send(CreateWord {
+                    user: bot.player.clone(),
+                    session_addr: ctx.address().into(),
+                    word,
+                    target_user_id: None,
+                }),
+                None => bot.room_manager.do_send(RoomNextTurn {
+                    user: bot.player.clone()
+                }),
+            }
+        });
+    }
+}
+
+impl Handler<CheckWordExisting> for BotSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: CheckWordExisting, _ctx: &mut Self::Context) {
+        let is_valid_word = player_has_letters_for_word(self.letters.clone(), msg.word.as_str())
+            && BOT_WORDS.lines().any(|word| word.eq_ignore_ascii_case(msg.word.as_str()));
+        let target_index = msg.target_index.filter(|_| is_valid_word);
+        self.room_manager.do_send(RoomWordValidated {
+            user: self.player.clone(),
+            word: msg.word,
+            target_index,
+        });
+
+        if target_index.is_some() {
+            self.room_manager.do_send(RoomRollDice {
+                user: self.player.clone(),
+            });
+        } else {
+            self.room_manager.do_send(RoomNextTurn {
+                user: self.player.clone()
+            });
+        }
+    }
+}
+
+impl Handler<DiceRollResult> for BotSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: DiceRollResult, _ctx: &mut Self::Context) {
+        self.letters = msg.new_letters;
+    }
+}
+
+impl Handler<RoomClosed> for BotSession {
+    type Result = ();
+
+    fn handle(&mut self, _msg: RoomClosed, ctx: &mut Self::Context) {
+        ctx.stop();
+    }
+}
+
+impl Handler<GameOver> for BotSession {
+    type Result = ();
+
+    fn handle(&mut self, _msg: GameOver, ctx: &mut Self::Context) {
+        ctx.stop();
+    }
+}
+
+macro_rules! ignore_messages {
+    ($($message:ty),*) => {
+        $(
+            impl Handler<$message> for BotSession {
+                type Result = ();
+
+                fn handle(&mut self, _msg: $message, _ctx: &mut Self::Context) {}
+            }
+        )*
+    };
+}
+
+impl Handler<LettersSilenced> for BotSession {
+    type Result = ();
+
+    fn handle(&mut self, _msg: LettersSilenced, _ctx: &mut Self::Context) {
+        self.room_manager.do_send(RoomNextTurn {
+            user: self.player.clone()
+        });
+    }
+}
+
+ignore_messages!(WordCreated, PlayerRolledDice, DamagePlayer, TakeDamage, PlayerDead, PlayerLeft, LobbyState, ReadyCheckFailed, ChatMessage, EmoteMessage, TilesRemaining, WordValidated, InvalidTarget, SpellEffect, HealPlayer, StatusEffectsUpdated, TeammateRack);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bot_users_are_derived_from_the_seed() {
+        let bot_user = |seed| create_bot_user(&mut ChaCha8Rng::seed_from_u64(seed));
+        assert_eq!(bot_user(7).id, bot_user(7).id);
+        assert_eq!(bot_user(7).name, bot_user(7).name);
+        assert_ne!(bot_user(7).id, bot_user(8).id);
+    }
+}
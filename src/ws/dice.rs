@@ -0,0 +1,69 @@
+use rand::Rng;
+
+const DIE_FACES: u32 = 6;
+
+pub struct DieRoll {
+    pub face: u32,
+    pub multiplier: f64,
+}
+
+impl DieRoll {
+    pub fn roll(rng: &mut impl Rng) -> DieRoll {
+        let face = rng.gen_range(1..=DIE_FACES);
+        DieRoll {
+            face,
+            multiplier: face_multiplier(face),
+        }
+    }
+
+    pub fn apply(&self, damage: u32) -> u32 {
+        (f64::from(damage) * self.multiplier).round() as u32
+    }
+}
+
+fn face_multiplier(face: u32) -> f64 {
+    match face {
+        1 => 0.0,
+        2 | 3 => 1.0,
+        4 | 5 => 1.5,
+        _ => 2.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+
+    #[test]
+    fn faces_map_to_their_multipliers() {
+        let multipliers: Vec<f64> = (1..=DIE_FACES).map(face_multiplier).collect();
+        assert_eq!(multipliers, vec![0.0, 1.0, 1.0, 1.5, 1.5, 2.0]);
+    }
+
+    #[test]
+    fn face_one_misses() {
+        let die_roll = DieRoll {
+            face: 1,
+            multiplier: face_multiplier(1),
+        };
+        assert_eq!(die_roll.apply(40), 0);
+    }
+
+    #[test]
+    fn rolls_apply_the_multiplier_of_their_face() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for _ in 0..100 {
+            let die_roll = DieRoll::roll(&mut rng);
+            assert!((1..=DIE_FACES).contains(&die_roll.face));
+            assert_eq!(die_roll.multiplier, face_multiplier(die_roll.face));
+        }
+        let die_roll = DieRoll {
+            face: 5,
+            multiplier: face_multiplier(5),
+        };
+        assert_eq!(die_roll.apply(7), 11);
+    }
+}
@@ -1,59 +1,73 @@
-use rand::seq::SliceRandom;
-
+use crate::language::language_registry::get_language_definition;
+use crate::model::language::Language;
 use crate::model::letter::Letter;
+use crate::model::tile_modifier::TileModifier;
 
-pub fn get_random_letters(amount: usize) -> Vec<Letter> {
-    let available_letters = get_available_letters();
-
-    available_letters.choose_multiple(
-        &mut rand::thread_rng(), amount,
-    ).cloned().collect()
-}
-
-pub fn get_word_value(word: String) -> u32 {
-    let available_letters = get_available_letters();
+pub fn get_word_value(language: Language, word: String) -> u32 {
+    let available_letters = get_language_definition(language).letters;
     let mut sum: u32 = 0;
     for c in word.chars() {
         let found_letter = available_letters.iter().find(|&l| {
-            l.letter.to_ascii_lowercase() == c.to_ascii_lowercase()
+            is_same_letter(l.letter, c)
         });
         let found_letter = match found_letter {
             Some(l) => l,
             None => { return 0; }
         };
-        sum = sum + found_letter.clone().value;
+        sum += found_letter.value;
     }
     sum
 }
 
-fn get_available_letters() -> Vec<Letter> {
-    vec![
-        Letter { letter: 'A', value: 1 },
-        Letter { letter: 'B', value: 3 },
-        Letter { letter: 'C', value: 3 },
-        Letter { letter: 'D', value: 2 },
-        Letter { letter: 'E', value: 1 },
-        Letter { letter: 'F', value: 4 },
-        Letter { letter: 'G', value: 2 },
-        Letter { letter: 'H', value: 2 },
-        Letter { letter: 'I', value: 1 },
-        Letter { letter: 'J', value: 8 },
-        Letter { letter: 'K', value: 5 },
-        Letter { letter: 'L', value: 1 },
-        Letter { letter: 'M', value: 3 },
-        Letter { letter: 'N', value: 1 },
-        Letter { letter: 'O', value: 1 },
-        Letter { letter: 'P', value: 3 },
-        Letter { letter: 'Q', value: 10 },
-        Letter { letter: 'R', value: 1 },
-        Letter { letter: 'S', value: 1 },
-        Letter { letter: 'T', value: 1 },
-        Letter { letter: 'U', value: 1 },
-        Letter { letter: 'V', value: 4 },
-        Letter { letter: 'W', value: 4 },
-        Letter { letter: 'X', value: 8 },
-        Letter { letter: 'Y', value: 4 },
-        Letter { letter: 'Z', value: 10 },
-    ]
+pub fn player_has_letters_for_word(letters: Vec<Letter>, word: &str) -> bool {
+    let mut letters_copy = letters.clone();
+    for c in word.chars() {
+        let found_letter_index = letters_copy.iter().position(|letter| {
+            is_same_letter(letter.letter, c)
+        });
+
+        match found_letter_index {
+            Some(index) => {
+                letters_copy.remove(index);
+            }
+            None => {
+                return false;
+            }
+        }
+    }
+
+    true
 }
 
+pub fn get_tiles_value(tiles: &[Letter]) -> u32 {
+    let letters_value: u32 = tiles.iter()
+        .map(|tile| match tile.modifier {
+            Some(TileModifier::DoubleLetter) => tile.value * 2,
+            _ => tile.value,
+        })
+        .sum();
+    let triple_words = tiles.iter()
+        .filter(|tile| tile.modifier == Some(TileModifier::TripleWord))
+        .count();
+    (0..triple_words).fold(letters_value, |value, _| value * 3)
+}
+
+pub fn take_used_letters(letters: &mut Vec<Letter>, word: &str) -> Vec<Letter> {
+    let mut used_letters = Vec::new();
+    for c in word.chars() {
+        let special_tile = letters.iter().position(|l| {
+            is_same_letter(l.letter, c) && l.modifier.is_some()
+        });
+        let tile = special_tile.or_else(|| letters.iter().position(|l| {
+            is_same_letter(l.letter, c)
+        }));
+        if let Some(pos) = tile {
+            used_letters.push(letters.remove(pos));
+        }
+    }
+    used_letters
+}
+
+fn is_same_letter(a: char, b: char) -> bool {
+    a.to_lowercase().eq(b.to_lowercase())
+}
@@ -0,0 +1,40 @@
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+
+use crate::model::match_record::MatchEvent;
+use crate::model::ws_response::WsResponse;
+
+pub struct MatchLog {
+    created_at: DateTime<Utc>,
+    started: Instant,
+    events: Vec<MatchEvent>,
+}
+
+impl MatchLog {
+    pub fn new() -> MatchLog {
+        MatchLog {
+            created_at: Utc::now(),
+            started: Instant::now(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, player_index: Option<usize>, event: WsResponse) {
+        let offset_millis = u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        self.events.push(MatchEvent {
+            timestamp: Utc::now(),
+            offset_millis,
+            player_index,
+            event,
+        });
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn events(&self) -> &[MatchEvent] {
+        &self.events
+    }
+}
@@ -0,0 +1,97 @@
+use std::time::Instant;
+
+use actix::Addr;
+
+use crate::model::user::User;
+use crate::util::constants::{RANKED_RATING_WINDOW, RANKED_RATING_WINDOW_GROWTH_PER_SECOND};
+use crate::ws::player_session::PlayerSession;
+
+pub struct QueuedPlayer {
+    pub user: User,
+    pub session_addr: Addr<PlayerSession>,
+    pub queued_at: Instant,
+}
+
+impl QueuedPlayer {
+    pub fn new(user: User, session_addr: Addr<PlayerSession>) -> QueuedPlayer {
+        QueuedPlayer {
+            user,
+            session_addr,
+            queued_at: Instant::now(),
+        }
+    }
+
+    fn rating_window(&self) -> i32 {
+        let waited_seconds = i32::try_from(self.queued_at.elapsed().as_secs()).unwrap_or(i32::MAX);
+        RANKED_RATING_WINDOW.saturating_add(waited_seconds.saturating_mul(RANKED_RATING_WINDOW_GROWTH_PER_SECOND))
+    }
+
+    fn accepts(&self, other: &QueuedPlayer) -> bool {
+        let difference = (self.user.rating - other.user.rating).abs();
+        difference <= self.rating_window() && difference <= other.rating_window()
+    }
+}
+
+pub fn find_ranked_group(queue: &[QueuedPlayer], group_size: usize) -> Option<Vec<usize>> {
+    for (anchor_index, anchor) in queue.iter().enumerate() {
+        let mut candidates: Vec<usize> = queue.iter().enumerate()
+            .filter(|&(index, player)| index != anchor_index && anchor.accepts(player))
+            .map(|(index, _)| index)
+            .collect();
+
+        if candidates.len() + 1 >= group_size {
+            candidates.sort_by_key(|&index| (queue[index].user.rating - anchor.user.rating).abs());
+            candidates.truncate(group_size - 1);
+            candidates.push(anchor_index);
+            return Some(candidates);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use actix::dev::channel::channel;
+
+    use super::*;
+
+    fn queued_player(rating: i32, waited_seconds: u64) -> QueuedPlayer {
+        let (sender, _) = channel::<PlayerSession>(1);
+        QueuedPlayer {
+            user: User {
+                id: format!("player-{}", rating),
+                name: String::new(),
+                email: String::new(),
+                photo: String::new(),
+                provider: String::new(),
+                rating,
+            },
+            session_addr: Addr::new(sender),
+            queued_at: Instant::now() - Duration::from_secs(waited_seconds),
+        }
+    }
+
+    #[test]
+    fn players_outside_the_rating_window_are_not_grouped() {
+        let queue = [queued_player(1200, 0), queued_player(1200 + RANKED_RATING_WINDOW + 50, 0)];
+        assert_eq!(find_ranked_group(&queue, 2), None);
+    }
+
+    #[test]
+    fn rating_window_widens_while_players_wait() {
+        let waited_seconds = (50 / RANKED_RATING_WINDOW_GROWTH_PER_SECOND + 1) as u64;
+        let queue = [queued_player(1200, waited_seconds), queued_player(1200 + RANKED_RATING_WINDOW + 50, 0)];
+        assert_eq!(find_ranked_group(&queue, 2), None);
+
+        let queue = [queued_player(1200, waited_seconds), queued_player(1200 + RANKED_RATING_WINDOW + 50, waited_seconds)];
+        assert_eq!(find_ranked_group(&queue, 2), Some(vec![1, 0]));
+    }
+
+    #[test]
+    fn groups_take_the_closest_ratings() {
+        let queue = [queued_player(1200, 0), queued_player(1290, 0), queued_player(1210, 0), queued_player(1250, 0)];
+        assert_eq!(find_ranked_group(&queue, 3), Some(vec![2, 3, 0]));
+    }
+}
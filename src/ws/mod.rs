@@ -3,3 +3,12 @@ pub mod player_session;
 pub mod ws_route;
 pub mod letters;
 pub mod room;
+pub mod rating;
+pub mod matchmaking;
+pub mod bot_session;
+pub mod session_addr;
+pub mod tile_bag;
+pub mod match_log;
+pub mod dice;
+pub mod player_match_stats;
+pub mod achievement_engine;
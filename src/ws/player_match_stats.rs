@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Default)]
+pub struct PlayerMatchStats {
+    pub words_submitted: u32,
+    pub words_accepted: u32,
+    pub damage_dealt: u32,
+    pub longest_word: String,
+    pub highest_damage_word: Option<(String, u32)>,
+    pub letters_used: HashMap<char, u32>,
+    pub turn_millis: u64,
+    pub timed_turns: u32,
+}
+
+impl PlayerMatchStats {
+    pub fn record_submission(&mut self, turn_duration: Duration) {
+        self.words_submitted += 1;
+        self.turn_millis += u64::try_from(turn_duration.as_millis()).unwrap_or(u64::MAX);
+        self.timed_turns += 1;
+    }
+
+    pub fn record_accepted_word(&mut self, word: &str) {
+        self.words_accepted += 1;
+        if word.chars().count() > self.longest_word.chars().count() {
+            self.longest_word = word.to_string();
+        }
+        for letter in word.chars().flat_map(char::to_lowercase) {
+            *self.letters_used.entry(letter).or_insert(0) += 1;
+        }
+    }
+
+    pub fn record_damage(&mut self, word: &str, damage: u32) {
+        self.damage_dealt += damage;
+        let is_highest = self.highest_damage_word.as_ref()
+            .is_none_or(|(_, highest_damage)| damage > *highest_damage);
+        if damage > 0 && is_highest {
+            self.highest_damage_word = Some((word.to_string(), damage));
+        }
+    }
+}
@@ -1,102 +1,359 @@
-use std::time::Duration;
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, Instant};
 
-use actix::{Actor, Addr, AsyncContext, Handler, ResponseFuture, SpawnHandle, StreamHandler};
+use actix::{Actor, ActorContext, ActorFutureExt, Addr, AsyncContext, Handler, ResponseFuture, SpawnHandle, StreamHandler, WrapFuture};
 use actix_web_actors::ws;
-use rand::seq::SliceRandom;
+use actix_web::web::Data;
 
 use crate::model::letter::Letter;
-use crate::model::player_session_messages::{CanRollDice, CheckWordExisting, DamagePlayer, DiceRolled, NextTurn, PlayerDead, StartPreparationTime, TakeDamage, WordCreated, WordDoesNotExist, WordExists};
-use crate::model::room_manager_messages::{CreateWord, Join, RoomDamagePlayer, RoomNextTurn, RoomNextTurnTimeoutInit, RoomPlayerDead};
+use crate::model::match_record::MatchRecord;
+use crate::model::player_session_messages::{AchievementUnlocked, ActiveRooms, CanRollDice, ChatMessage, CheckWordExisting, DamagePlayer, DiceRollResult, DiceRolled, DictionaryUnavailable, EmoteMessage, GameOver, GameState, HealPlayer, InvalidTarget, LettersSilenced, LobbyState, NextTurn, PlayerDead, PlayerLeft, PlayerRolledDice, ReadyCheckFailed, RoomCreated, RoomNotFound, SpellEffect, StartPreparationTime, StatusEffectsUpdated, TakeDamage, TeammateRack, TournamentMatchReady, TournamentUpdated, TilesRemaining, WordCreated, WordDoesNotExist, WordExists, WordValidated};
+use crate::model::room_manager_messages::{CreatePrivateRoom, CreateWord, Join, JoinRanked, JoinRoom, Leave, ListRooms, Practice, RoomChat, RoomEmote, RoomNextTurn, RoomNextTurnTimeoutInit, RoomReady, RoomRollDice, RoomWordCheckFailed, RoomWordValidated, Spectate, StartPrivateRoom, StopSpectating};
+use crate::model::replay_speed::ReplaySpeed;
+use crate::model::room_rules::RoomRules;
+use crate::model::tournament_manager_messages::{SubscribeTournaments, UnsubscribeTournaments};
 use crate::model::user::User;
 use crate::model::ws_request::WsRequest;
-use crate::model::ws_response::{DiceRolledResponse, WsResponse};
+use crate::model::ws_response::WsResponse;
+use crate::repository::mongo_db_match_repository::MongoDBMatchRepository;
 use crate::service::dictionary_service::DictionaryService;
-use crate::util::constants::{MAX_PLAYERS_PER_ROOM, ROLL_DICE_SECONDS, TURN_SECONDS};
-use crate::ws::letters::{get_random_letters, get_word_value};
+use crate::service::match_service::MatchService;
+use crate::util::constants::{CHAT_MAX_LENGTH, CHAT_RATE_LIMIT_MESSAGES, CHAT_RATE_LIMIT_SECONDS};
+use crate::ws::letters::player_has_letters_for_word;
+use crate::tournament::tournament_manager::TournamentManager;
 use crate::ws::room_manager::RoomManager;
 
 pub struct PlayerSession {
     pub player: User,
-    pub health: u32,
     pub letters: Vec<Letter>,
+    pub rules: RoomRules,
     pub room_manager: Addr<RoomManager>,
+    pub tournament_manager: Addr<TournamentManager>,
+    pub dictionary_service: Data<DictionaryService>,
+    pub match_service: Data<MatchService<MongoDBMatchRepository>>,
     pub last_ws_response: Option<WsResponse>,
-    pub last_word_exists: WordExists,
     pub roll_dice_timeout: Option<SpawnHandle>,
+    pub spectating: bool,
+    pub chat_timestamps: VecDeque<Instant>,
+    pub muted_user_ids: HashSet<String>,
+    pub replay_timeouts: Vec<SpawnHandle>,
 }
 
 impl PlayerSession {
-    pub fn new(player: User, room_manager: Addr<RoomManager>) -> PlayerSession {
+    pub fn new(
+        player: User,
+        room_manager: Addr<RoomManager>,
+        tournament_manager: Addr<TournamentManager>,
+        dictionary_service: Data<DictionaryService>,
+        match_service: Data<MatchService<MongoDBMatchRepository>>,
+    ) -> PlayerSession {
         PlayerSession {
             player,
-            health: 100,
             letters: Vec::new(),
+            rules: RoomRules::default(),
             room_manager,
+            tournament_manager,
+            dictionary_service,
+            match_service,
             last_ws_response: None,
-            last_word_exists: WordExists {
-                word: String::new(),
-                damage: 0,
-                player_index: 0,
-            },
             roll_dice_timeout: None,
+            spectating: false,
+            chat_timestamps: VecDeque::new(),
+            muted_user_ids: HashSet::new(),
+            replay_timeouts: Vec::new(),
+        }
+    }
+
+    fn is_chat_rate_limited(&mut self) -> bool {
+        let window = Duration::from_secs(CHAT_RATE_LIMIT_SECONDS);
+        while let Some(timestamp) = self.chat_timestamps.front() {
+            if timestamp.elapsed() < window {
+                break;
+            }
+            self.chat_timestamps.pop_front();
+        }
+
+        if self.chat_timestamps.len() >= CHAT_RATE_LIMIT_MESSAGES {
+            return true;
+        }
+        self.chat_timestamps.push_back(Instant::now());
+        false
+    }
+
+    fn can_join(&mut self, ctx: &mut <Self as Actor>::Context) -> bool {
+        match self.last_ws_response {
+            None => {
+                self.stop_replay(ctx);
+                true
+            }
+            Some(WsResponse::GameOver(_)) => {
+                self.letters = Vec::new();
+                self.last_ws_response = None;
+                self.spectating = false;
+                self.stop_replay(ctx);
+                true
+            }
+            Some(_) if self.spectating => {
+                self.stop_spectating(ctx);
+                self.stop_replay(ctx);
+                true
+            }
+            Some(_) => false,
+        }
+    }
+
+    fn stop_spectating(&mut self, ctx: &mut <Self as Actor>::Context) {
+        self.room_manager.do_send(StopSpectating {
+            session_addr: ctx.address(),
+        });
+        self.letters = Vec::new();
+        self.last_ws_response = None;
+        self.spectating = false;
+    }
+
+    fn start_replay(&mut self, match_id: String, match_record: Option<MatchRecord>, speed: ReplaySpeed, ctx: &mut <Self as Actor>::Context) {
+        let match_record = match match_record {
+            Some(match_record) => match_record,
+            None => {
+                ctx.address().do_send(RoomNotFound { code: match_id });
+                return;
+            }
+        };
+
+        for match_event in match_record.visible_to(&self.player.id).events {
+            let delay = Duration::from_millis(match_event.offset_millis / speed.multiplier());
+            let replay_event = move |_session: &mut PlayerSession, ctx: &mut <Self as Actor>::Context| {
+                if let Ok(json) = serde_json::to_string(&match_event.event) {
+                    ctx.text(json);
+                }
+            };
+            self.replay_timeouts.push(ctx.run_later(delay, replay_event));
+        }
+    }
+
+    fn stop_replay(&mut self, ctx: &mut <Self as Actor>::Context) {
+        for handle in self.replay_timeouts.drain(..) {
+            ctx.cancel_future(handle);
         }
     }
 }
 
 impl Actor for PlayerSession {
     type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        self.tournament_manager.do_send(SubscribeTournaments {
+            user: self.player.clone(),
+            session_addr: ctx.address(),
+        });
+    }
+
+    fn stopped(&mut self, ctx: &mut Self::Context) {
+        self.room_manager.do_send(Leave {
+            user: self.player.clone(),
+            session_addr: ctx.address(),
+        });
+        self.tournament_manager.do_send(UnsubscribeTournaments {
+            user: self.player.clone(),
+            session_addr: ctx.address(),
+        });
+    }
 }
 
 impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for PlayerSession {
     fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
-        match msg {
-            Ok(ws::Message::Text(text)) => {
-                let request: Result<WsRequest, serde_json::Error> = serde_json::from_str(text.to_string().as_str());
-                let request = match request {
-                    Ok(req) => req,
-                    Err(_) => return
-                };
-
-                match request {
-                    WsRequest::Join => {
-                        if let Some(_) = self.last_ws_response.clone() {
-                            return;
+        if let Ok(ws::Message::Close(reason)) = msg {
+            ctx.close(reason);
+            ctx.stop();
+        } else if let Ok(ws::Message::Text(text)) = msg {
+            let request: Result<WsRequest, serde_json::Error> = serde_json::from_str(text.to_string().as_str());
+            let request = match request {
+                Ok(req) => req,
+                Err(_) => return
+            };
+
+            match request {
+                WsRequest::Join(language) => {
+                    if !self.can_join(ctx) {
+                        return;
+                    }
+
+                    self.room_manager.do_send(
+                        Join {
+                            user: self.player.clone(),
+                            session_addr: ctx.address(),
+                            language: language.unwrap_or_default(),
                         }
+                    );
+                }
+                WsRequest::JoinRanked => {
+                    if !self.can_join(ctx) {
+                        return;
+                    }
 
-                        self.room_manager.do_send(
-                            Join {
-                                user: self.player.clone(),
-                                session_addr: ctx.address(),
-                            }
-                        );
+                    self.room_manager.do_send(
+                        JoinRanked {
+                            user: self.player.clone(),
+                            session_addr: ctx.address(),
+                        }
+                    );
+                }
+                WsRequest::CreatePrivateRoom(rules) => {
+                    if !self.can_join(ctx) {
+                        return;
                     }
-                    WsRequest::CreateWord(word) => {
-                        if let Some(ws_response) = self.last_ws_response.clone() {
-                            if let WsResponse::NextTurn(_) = ws_response {
-                                self.room_manager.do_send(
-                                    CreateWord {
-                                        user: self.player.clone(),
-                                        session_addr: ctx.address(),
-                                        word,
-                                    }
-                                )
-                            }
+
+                    self.room_manager.do_send(
+                        CreatePrivateRoom {
+                            user: self.player.clone(),
+                            session_addr: ctx.address(),
+                            rules,
                         }
+                    );
+                }
+                WsRequest::JoinRoom { code } => {
+                    if !self.can_join(ctx) {
+                        return;
                     }
-                    WsRequest::RollDice => {
-                        if let Some(ws_response) = self.last_ws_response.clone() {
-                            if let WsResponse::CanRollDice(_) = ws_response {
-                                ctx.address().do_send(
-                                    DiceRolled {
-                                        word_exists_event: self.last_word_exists.clone()
-                                    }
-                                );
-                            }
+
+                    self.room_manager.do_send(
+                        JoinRoom {
+                            user: self.player.clone(),
+                            session_addr: ctx.address(),
+                            code,
+                        }
+                    );
+                }
+                WsRequest::StartGame => {
+                    self.room_manager.do_send(
+                        StartPrivateRoom {
+                            user: self.player.clone(),
                         }
+                    );
+                }
+                WsRequest::Spectate { room_id } => {
+                    if !self.can_join(ctx) {
+                        return;
+                    }
+
+                    self.room_manager.do_send(
+                        Spectate {
+                            room_id,
+                            session_addr: ctx.address(),
+                        }
+                    );
+                }
+                WsRequest::StopSpectating => {
+                    if self.spectating {
+                        self.stop_spectating(ctx);
+                    }
+                }
+                WsRequest::ListRooms => {
+                    self.room_manager.do_send(
+                        ListRooms {
+                            session_addr: ctx.address(),
+                        }
+                    );
+                }
+                WsRequest::Chat(message) => {
+                    let message: String = message.trim().chars().take(CHAT_MAX_LENGTH).collect();
+                    if message.is_empty() || self.is_chat_rate_limited() {
+                        return;
+                    }
+
+                    self.room_manager.do_send(
+                        RoomChat {
+                            user: self.player.clone(),
+                            message,
+                        }
+                    );
+                }
+                WsRequest::Emote(emote) => {
+                    if self.is_chat_rate_limited() {
+                        return;
+                    }
+
+                    self.room_manager.do_send(
+                        RoomEmote {
+                            user: self.player.clone(),
+                            emote,
+                        }
+                    );
+                }
+                WsRequest::Mute { user_id } => {
+                    self.muted_user_ids.insert(user_id);
+                }
+                WsRequest::Unmute { user_id } => {
+                    self.muted_user_ids.remove(&user_id);
+                }
+                WsRequest::Practice(difficulty) => {
+                    if !self.can_join(ctx) {
+                        return;
+                    }
+
+                    self.room_manager.do_send(
+                        Practice {
+                            user: self.player.clone(),
+                            session_addr: ctx.address(),
+                            difficulty,
+                        }
+                    );
+                }
+                WsRequest::Replay { match_id, speed } => {
+                    if !self.can_join(ctx) {
+                        return;
+                    }
+
+                    let match_service = self.match_service.clone();
+                    let load_match = async move {
+                        let match_record = match_service.get_match(&match_id).await;
+                        (match_id, match_record)
+                    };
+                    let on_match_loaded = load_match.into_actor(self).map(move |(match_id, match_record), session, ctx| {
+                        session.start_replay(match_id, match_record, speed.unwrap_or_default(), ctx);
+                    });
+                    ctx.spawn(on_match_loaded);
+                }
+                WsRequest::Ready => {
+                    self.room_manager.do_send(
+                        RoomReady {
+                            user: self.player.clone(),
+                            ready: true,
+                        }
+                    );
+                }
+                WsRequest::Unready => {
+                    self.room_manager.do_send(
+                        RoomReady {
+                            user: self.player.clone(),
+                            ready: false,
+                        }
+                    );
+                }
+                WsRequest::CreateWord(word_submission) => {
+                    if self.spectating {
+                        return;
+                    }
+                    if let Some(WsResponse::NextTurn(_) | WsResponse::GameState(_) | WsResponse::DictionaryUnavailable(_)) = self.last_ws_response {
+                        let (word, target_user_id) = word_submission.into_parts();
+                        self.room_manager.do_send(
+                            CreateWord {
+                                user: self.player.clone(),
+                                session_addr: ctx.address().into(),
+                                word,
+                                target_user_id,
+                            }
+                        )
+                    }
+                }
+                WsRequest::RollDice => {
+                    if let Some(WsResponse::CanRollDice(_)) = self.last_ws_response {
+                        ctx.address().do_send(
+                            DiceRolled
+                        );
                     }
                 }
             }
-            _ => (),
         }
     }
 }
@@ -117,8 +374,11 @@ impl Handler<NextTurn> for PlayerSession {
                 return;
             }
         };
-        let _ = ctx.text(next_turn_json);
+        ctx.text(next_turn_json);
 
+        if self.spectating {
+            return;
+        }
         self.room_manager.do_send(RoomNextTurnTimeoutInit {
             user: self.player.clone()
         });
@@ -130,6 +390,7 @@ impl Handler<StartPreparationTime> for PlayerSession {
 
     fn handle(&mut self, msg: StartPreparationTime, ctx: &mut Self::Context) {
         self.letters = msg.letters.clone();
+        self.rules = msg.rules.clone();
         let start_preparation_time_event = WsResponse::StartPreparationTime(msg.clone());
         self.last_ws_response = Some(start_preparation_time_event.clone());
         let start_preparation_time_json = serde_json::to_string(&start_preparation_time_event);
@@ -140,14 +401,14 @@ impl Handler<StartPreparationTime> for PlayerSession {
             }
         };
 
-        let _ = ctx.text(start_preparation_time_json);
-        ctx.run_later(Duration::from_secs(msg.seconds), |_, ctx| {
+        ctx.text(start_preparation_time_json);
+        ctx.run_later(Duration::from_secs(msg.seconds), |session, ctx| {
             let start_game_event = NextTurn {
                 player_index: 0,
-                seconds: TURN_SECONDS,
+                seconds: session.rules.turn_seconds,
             };
 
-            let _ = ctx.address().do_send(start_game_event);
+            ctx.address().do_send(start_game_event);
         });
     }
 }
@@ -165,7 +426,7 @@ impl Handler<WordCreated> for PlayerSession {
                 return;
             }
         };
-        let _ = ctx.text(word_created_json);
+        ctx.text(word_created_json);
     }
 }
 
@@ -175,22 +436,33 @@ impl Handler<CheckWordExisting> for PlayerSession {
     fn handle(&mut self, msg: CheckWordExisting, ctx: &mut Self::Context) -> Self::Result {
         let address = ctx.address();
         let has_letters = player_has_letters_for_word(self.letters.clone(), msg.word.as_str());
+        let rules = self.rules.clone();
+        let dictionary_service = self.dictionary_service.clone();
         let future = async move {
-            if word_exists(msg.word.as_str()).await && has_letters {
-                let max_players = u32::try_from(MAX_PLAYERS_PER_ROOM).unwrap_or(0);
-                let mut other_player_indices: Vec<u32> = (0..max_players).collect();
-                other_player_indices.remove(msg.player_index);
-
-                let player_index = (*other_player_indices.choose(&mut rand::thread_rng()).unwrap()).try_into().unwrap();
-
-                address.do_send(WordExists {
-                    word: msg.word.clone(),
-                    damage: get_word_value(msg.word.clone()),
-                    player_index,
-                });
+            let word_exists = if has_letters {
+                dictionary_service.word_exists(rules.language, msg.word.as_str()).await
             } else {
-                let word_not_found_event = WordDoesNotExist {};
-                let _ = address.do_send(word_not_found_event);
+                Ok(false)
+            };
+            match (word_exists, msg.target_index) {
+                (Err(_), _) => {
+                    address.do_send(DictionaryUnavailable {
+                        word: msg.word.clone(),
+                        seconds: rules.turn_seconds,
+                    });
+                }
+                (Ok(true), Some(player_index)) => {
+                    address.do_send(WordExists {
+                        word: msg.word.clone(),
+                        player_index,
+                    });
+                }
+                _ => {
+                    let word_not_found_event = WordDoesNotExist {
+                        word: msg.word.clone(),
+                    };
+                    address.do_send(word_not_found_event);
+                }
             }
         };
 
@@ -198,31 +470,6 @@ impl Handler<CheckWordExisting> for PlayerSession {
     }
 }
 
-async fn word_exists(word: &str) -> bool {
-    let dictionary_service = DictionaryService::new();
-    dictionary_service.word_exists(word.to_ascii_lowercase().as_str()).await.unwrap_or(false)
-}
-
-fn player_has_letters_for_word(letters: Vec<Letter>, word: &str) -> bool {
-    let mut letters_copy = letters.clone();
-    for c in word.chars() {
-        let found_letter_index = letters_copy.iter().position(|letter| {
-            letter.letter.to_ascii_lowercase() == c.to_ascii_lowercase()
-        });
-
-        match found_letter_index {
-            Some(index) => {
-                letters_copy.remove(index);
-            }
-            None => {
-                return false;
-            }
-        }
-    }
-
-    true
-}
-
 impl Handler<CanRollDice> for PlayerSession {
     type Result = ();
 
@@ -243,28 +490,59 @@ impl Handler<CanRollDice> for PlayerSession {
 impl Handler<WordDoesNotExist> for PlayerSession {
     type Result = ();
 
-    fn handle(&mut self, _msg: WordDoesNotExist, _ctx: &mut Self::Context) {
+    fn handle(&mut self, msg: WordDoesNotExist, _ctx: &mut Self::Context) {
+        self.room_manager.do_send(RoomWordValidated {
+            user: self.player.clone(),
+            word: msg.word,
+            target_index: None,
+        });
         self.room_manager.do_send(RoomNextTurn {
             user: self.player.clone()
         });
     }
 }
 
+impl Handler<DictionaryUnavailable> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: DictionaryUnavailable, ctx: &mut Self::Context) {
+        let dictionary_unavailable_message = WsResponse::DictionaryUnavailable(msg);
+        self.last_ws_response = Some(dictionary_unavailable_message.clone());
+        let dictionary_unavailable_json = serde_json::to_string(&dictionary_unavailable_message);
+        let dictionary_unavailable_json = match dictionary_unavailable_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(dictionary_unavailable_json);
+
+        self.room_manager.do_send(RoomWordCheckFailed {
+            user: self.player.clone()
+        });
+        self.room_manager.do_send(RoomNextTurnTimeoutInit {
+            user: self.player.clone()
+        });
+    }
+}
+
 impl Handler<WordExists> for PlayerSession {
     type Result = ();
 
     fn handle(&mut self, msg: WordExists, ctx: &mut Self::Context) {
-        self.last_word_exists = msg.clone();
+        self.room_manager.do_send(RoomWordValidated {
+            user: self.player.clone(),
+            word: msg.word.clone(),
+            target_index: Some(msg.player_index),
+        });
 
-        ctx.address().do_send(CanRollDice { seconds: ROLL_DICE_SECONDS });
+        ctx.address().do_send(CanRollDice { seconds: self.rules.roll_dice_seconds });
 
         let roll_dice_timeout_future = move |_session: &mut PlayerSession, ctx: &mut Self::Context| {
-            ctx.address().do_send(DiceRolled {
-                word_exists_event: msg
-            });
+            ctx.address().do_send(DiceRolled);
         };
         self.roll_dice_timeout = Some(
-            ctx.run_later(Duration::from_secs(ROLL_DICE_SECONDS), roll_dice_timeout_future)
+            ctx.run_later(Duration::from_secs(self.rules.roll_dice_seconds), roll_dice_timeout_future)
         );
     }
 }
@@ -272,14 +550,23 @@ impl Handler<WordExists> for PlayerSession {
 impl Handler<DiceRolled> for PlayerSession {
     type Result = ();
 
-    fn handle(&mut self, msg: DiceRolled, ctx: &mut Self::Context) {
-        self.letters = remove_used_letters(self.letters.clone(), msg.word_exists_event.word.clone());
-        self.letters = draw_missing_letters(self.letters.clone(), msg.word_exists_event.word.clone());
-
-        let dice_rolled_message = WsResponse::DiceRolledResponse(DiceRolledResponse {
-            amount: msg.word_exists_event.player_index.clone(),
-            new_letters: self.letters.clone(),
+    fn handle(&mut self, _msg: DiceRolled, ctx: &mut Self::Context) {
+        if let Some(handle) = self.roll_dice_timeout.take() {
+            ctx.cancel_future(handle);
+        }
+        self.room_manager.do_send(RoomRollDice {
+            user: self.player.clone(),
         });
+    }
+}
+
+impl Handler<DiceRollResult> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: DiceRollResult, ctx: &mut Self::Context) {
+        self.letters = msg.new_letters.clone();
+
+        let dice_rolled_message = WsResponse::DiceRolledResponse(msg);
         self.last_ws_response = Some(dice_rolled_message.clone());
         let dice_rolled_json = serde_json::to_string(&dice_rolled_message);
         let dice_rolled_json = match dice_rolled_json {
@@ -289,38 +576,7 @@ impl Handler<DiceRolled> for PlayerSession {
             }
         };
         ctx.text(dice_rolled_json);
-
-        self.room_manager.do_send(RoomDamagePlayer {
-            user: self.player.clone(),
-            damage: msg.word_exists_event.damage.clone(),
-            player_index: msg.word_exists_event.player_index.clone(),
-        });
-        self.room_manager.do_send(RoomNextTurn {
-            user: self.player.clone()
-        });
-    }
-}
-
-fn remove_used_letters(letters: Vec<Letter>, word: String) -> Vec<Letter> {
-    let mut result = letters.clone();
-    for c in word.chars() {
-        if let Some(pos) = result.iter().position(|l| {
-            l.letter.to_ascii_lowercase() == c.to_ascii_lowercase()
-        }) {
-            result.remove(pos);
-        }
     }
-    result
-}
-
-fn draw_missing_letters(letters: Vec<Letter>, word: String) -> Vec<Letter> {
-    let mut result = letters.clone();
-    let letters_count = letters.iter().count();
-    let word_count = word.chars().count();
-    let missing_letters_count = letters_count - word_count;
-    let missing_letters = get_random_letters(missing_letters_count);
-    missing_letters.iter().for_each(|l| result.push(l.clone()));
-    result
 }
 
 impl Handler<DamagePlayer> for PlayerSession {
@@ -344,14 +600,6 @@ impl Handler<TakeDamage> for PlayerSession {
     type Result = ();
 
     fn handle(&mut self, msg: TakeDamage, ctx: &mut Self::Context) {
-        self.health = self.health.clone() - msg.damage;
-        if self.health.clone() <= 0 {
-            self.room_manager.do_send(RoomPlayerDead {
-                user: self.player.clone(),
-                player_index: msg.player_index.clone(),
-            });
-        }
-
         let damage_player_message = WsResponse::TakeDamage(msg.clone());
         self.last_ws_response = Some(damage_player_message.clone());
         let damage_player_json = serde_json::to_string(&damage_player_message);
@@ -379,4 +627,382 @@ impl Handler<PlayerDead> for PlayerSession {
         };
         ctx.text(player_dead_json);
     }
-}
\ No newline at end of file
+}
+
+impl Handler<PlayerLeft> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: PlayerLeft, ctx: &mut Self::Context) {
+        let player_left_message = WsResponse::PlayerLeft(msg.clone());
+        let player_left_json = serde_json::to_string(&player_left_message);
+        let player_left_json = match player_left_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(player_left_json);
+    }
+}
+
+impl Handler<RoomCreated> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomCreated, ctx: &mut Self::Context) {
+        let room_created_message = WsResponse::RoomCreated(msg.clone());
+        self.last_ws_response = Some(room_created_message.clone());
+        let room_created_json = serde_json::to_string(&room_created_message);
+        let room_created_json = match room_created_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(room_created_json);
+    }
+}
+
+impl Handler<RoomNotFound> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomNotFound, ctx: &mut Self::Context) {
+        let room_not_found_message = WsResponse::RoomNotFound(msg.clone());
+        let room_not_found_json = serde_json::to_string(&room_not_found_message);
+        let room_not_found_json = match room_not_found_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(room_not_found_json);
+    }
+}
+
+impl Handler<ActiveRooms> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: ActiveRooms, ctx: &mut Self::Context) {
+        let active_rooms_message = WsResponse::ActiveRooms(msg.clone());
+        let active_rooms_json = serde_json::to_string(&active_rooms_message);
+        let active_rooms_json = match active_rooms_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(active_rooms_json);
+    }
+}
+
+impl Handler<ChatMessage> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: ChatMessage, ctx: &mut Self::Context) {
+        if self.muted_user_ids.contains(&msg.user_id) {
+            return;
+        }
+
+        let chat_message = WsResponse::Chat(msg.clone());
+        let chat_json = serde_json::to_string(&chat_message);
+        let chat_json = match chat_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(chat_json);
+    }
+}
+
+impl Handler<EmoteMessage> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: EmoteMessage, ctx: &mut Self::Context) {
+        if self.muted_user_ids.contains(&msg.user_id) {
+            return;
+        }
+
+        let emote_message = WsResponse::Emote(msg.clone());
+        let emote_json = serde_json::to_string(&emote_message);
+        let emote_json = match emote_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(emote_json);
+    }
+}
+
+impl Handler<LobbyState> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: LobbyState, ctx: &mut Self::Context) {
+        let lobby_state_message = WsResponse::LobbyState(msg.clone());
+        let lobby_state_json = serde_json::to_string(&lobby_state_message);
+        let lobby_state_json = match lobby_state_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(lobby_state_json);
+    }
+}
+
+impl Handler<ReadyCheckFailed> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: ReadyCheckFailed, ctx: &mut Self::Context) {
+        self.last_ws_response = None;
+
+        let ready_check_failed_message = WsResponse::ReadyCheckFailed(msg);
+        let ready_check_failed_json = serde_json::to_string(&ready_check_failed_message);
+        let ready_check_failed_json = match ready_check_failed_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(ready_check_failed_json);
+    }
+}
+
+impl Handler<GameOver> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: GameOver, ctx: &mut Self::Context) {
+        if let Some(future) = self.roll_dice_timeout.take() {
+            ctx.cancel_future(future);
+        }
+
+        let own_result = msg.players.iter().find(|player| player.user.id == self.player.id);
+        if let Some(rating) = own_result.and_then(|player| player.rating) {
+            self.player.rating = rating;
+        }
+
+        let game_over_message = WsResponse::GameOver(msg.clone());
+        self.last_ws_response = Some(game_over_message.clone());
+        let game_over_json = serde_json::to_string(&game_over_message);
+        let game_over_json = match game_over_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(game_over_json);
+    }
+}
+
+impl Handler<GameState> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: GameState, ctx: &mut Self::Context) {
+        self.rules = msg.rules.clone();
+        self.spectating = msg.player_index.is_none();
+        self.letters = msg.letters.clone();
+
+        let game_state_message = WsResponse::GameState(msg.clone());
+        self.last_ws_response = Some(game_state_message.clone());
+        let game_state_json = serde_json::to_string(&game_state_message);
+        let game_state_json = match game_state_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(game_state_json);
+    }
+}
+
+impl Handler<PlayerRolledDice> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: PlayerRolledDice, ctx: &mut Self::Context) {
+        let player_rolled_dice_message = WsResponse::PlayerRolledDice(msg);
+        let player_rolled_dice_json = serde_json::to_string(&player_rolled_dice_message);
+        let player_rolled_dice_json = match player_rolled_dice_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(player_rolled_dice_json);
+    }
+}
+
+impl Handler<TilesRemaining> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: TilesRemaining, ctx: &mut Self::Context) {
+        let tiles_remaining_message = WsResponse::TilesRemaining(msg);
+        let tiles_remaining_json = serde_json::to_string(&tiles_remaining_message);
+        let tiles_remaining_json = match tiles_remaining_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(tiles_remaining_json);
+    }
+}
+
+impl Handler<WordValidated> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: WordValidated, ctx: &mut Self::Context) {
+        let word_validated_message = WsResponse::WordValidated(msg);
+        let word_validated_json = serde_json::to_string(&word_validated_message);
+        let word_validated_json = match word_validated_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(word_validated_json);
+    }
+}
+
+impl Handler<InvalidTarget> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: InvalidTarget, ctx: &mut Self::Context) {
+        let invalid_target_message = WsResponse::InvalidTarget(msg);
+        let invalid_target_json = serde_json::to_string(&invalid_target_message);
+        let invalid_target_json = match invalid_target_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(invalid_target_json);
+    }
+}
+
+impl Handler<SpellEffect> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: SpellEffect, ctx: &mut Self::Context) {
+        let spell_effect_message = WsResponse::SpellEffect(msg);
+        let spell_effect_json = serde_json::to_string(&spell_effect_message);
+        let spell_effect_json = match spell_effect_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(spell_effect_json);
+    }
+}
+
+impl Handler<HealPlayer> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: HealPlayer, ctx: &mut Self::Context) {
+        let heal_player_message = WsResponse::HealPlayer(msg);
+        let heal_player_json = serde_json::to_string(&heal_player_message);
+        let heal_player_json = match heal_player_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(heal_player_json);
+    }
+}
+
+impl Handler<StatusEffectsUpdated> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: StatusEffectsUpdated, ctx: &mut Self::Context) {
+        let status_effects_updated_message = WsResponse::StatusEffectsUpdated(msg);
+        let status_effects_updated_json = serde_json::to_string(&status_effects_updated_message);
+        let status_effects_updated_json = match status_effects_updated_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(status_effects_updated_json);
+    }
+}
+
+impl Handler<LettersSilenced> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: LettersSilenced, ctx: &mut Self::Context) {
+        let letters_silenced_message = WsResponse::LettersSilenced(msg);
+        let letters_silenced_json = serde_json::to_string(&letters_silenced_message);
+        let letters_silenced_json = match letters_silenced_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(letters_silenced_json);
+    }
+}
+
+impl Handler<TeammateRack> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: TeammateRack, ctx: &mut Self::Context) {
+        let teammate_rack_message = WsResponse::TeammateRack(msg);
+        let teammate_rack_json = serde_json::to_string(&teammate_rack_message);
+        let teammate_rack_json = match teammate_rack_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(teammate_rack_json);
+    }
+}
+
+impl Handler<TournamentUpdated> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: TournamentUpdated, ctx: &mut Self::Context) {
+        let tournament_updated_message = WsResponse::TournamentUpdated(msg);
+        let tournament_updated_json = serde_json::to_string(&tournament_updated_message);
+        let tournament_updated_json = match tournament_updated_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(tournament_updated_json);
+    }
+}
+
+impl Handler<TournamentMatchReady> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: TournamentMatchReady, ctx: &mut Self::Context) {
+        let tournament_match_ready_message = WsResponse::TournamentMatchReady(msg);
+        let tournament_match_ready_json = serde_json::to_string(&tournament_match_ready_message);
+        let tournament_match_ready_json = match tournament_match_ready_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(tournament_match_ready_json);
+    }
+}
+
+impl Handler<AchievementUnlocked> for PlayerSession {
+    type Result = ();
+
+    fn handle(&mut self, msg: AchievementUnlocked, ctx: &mut Self::Context) {
+        let achievement_unlocked_message = WsResponse::AchievementUnlocked(msg);
+        let achievement_unlocked_json = serde_json::to_string(&achievement_unlocked_message);
+        let achievement_unlocked_json = match achievement_unlocked_json {
+            Ok(json) => json,
+            Err(_) => {
+                return;
+            }
+        };
+        ctx.text(achievement_unlocked_json);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::util::constants::RATING_K_FACTOR;
+
+pub fn calculate_ratings(ratings: &[i32], places: &[usize]) -> Vec<i32> {
+    let players_count = ratings.len();
+    if players_count < 2 {
+        return ratings.to_vec();
+    }
+
+    let k_factor = RATING_K_FACTOR / (players_count - 1) as f64;
+    (0..players_count).map(|player| {
+        let mut delta = 0.0;
+        for opponent in (0..players_count).filter(|&opponent| opponent != player) {
+            let expected = 1.0 / (1.0 + 10f64.powf(f64::from(ratings[opponent] - ratings[player]) / 400.0));
+            let score = match places[player].cmp(&places[opponent]) {
+                std::cmp::Ordering::Less => 1.0,
+                std::cmp::Ordering::Equal => 0.5,
+                std::cmp::Ordering::Greater => 0.0,
+            };
+            delta += k_factor * (score - expected);
+        }
+        ratings[player] + delta.round() as i32
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_player_matches_conserve_rating() {
+        for (ratings, places) in [([1200, 1200], [1, 2]), ([1500, 1100], [2, 1]), ([1350, 1280], [1, 2])] {
+            let new_ratings = calculate_ratings(&ratings, &places);
+            assert_eq!(new_ratings.iter().sum::<i32>(), ratings.iter().sum::<i32>());
+            let winner = places.iter().position(|&place| place == 1).unwrap();
+            assert!(new_ratings[winner] > ratings[winner]);
+        }
+    }
+
+    #[test]
+    fn free_for_all_conserves_rating_up_to_rounding() {
+        let ratings = [1400, 1250, 1200, 1010];
+        let new_ratings = calculate_ratings(&ratings, &[3, 1, 4, 2]);
+        let drift = new_ratings.iter().sum::<i32>() - ratings.iter().sum::<i32>();
+        assert!(drift.abs() <= ratings.len() as i32 / 2, "drift {}", drift);
+    }
+
+    #[test]
+    fn tied_places_count_as_draws() {
+        assert_eq!(calculate_ratings(&[1200, 1200], &[1, 1]), vec![1200, 1200]);
+
+        let new_ratings = calculate_ratings(&[1400, 1200], &[1, 1]);
+        assert!(new_ratings[0] < 1400);
+        assert!(new_ratings[1] > 1200);
+        assert_eq!(new_ratings[0] + new_ratings[1], 2600);
+    }
+
+    #[test]
+    fn single_players_keep_their_rating() {
+        assert_eq!(calculate_ratings(&[1300], &[1]), vec![1300]);
+    }
+}
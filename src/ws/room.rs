@@ -1,34 +1,360 @@
-use actix::{Addr, SpawnHandle};
+use std::time::{Duration, Instant};
 
-use crate::model::player_session_messages::{CheckWordExisting, DamagePlayer, NextTurn, PlayerDead, StartPreparationTime, TakeDamage, WordCreated};
-use crate::model::room_manager_messages::RoomPlayerDead;
-use crate::model::user::User;
-use crate::util::constants::*;
-use crate::ws::letters::get_random_letters;
+use actix::{Actor, Addr, Handler, Message, Recipient, SpawnHandle};
+use actix::dev::ToEnvelope;
+use rand::{Rng, SeedableRng};
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+use crate::model::achievement::AchievementId;
+use crate::model::language::Language;
+use crate::model::letter::Letter;
+use crate::model::match_record::{MatchPlayerSummary, MatchRecord, WordDamage};
+use crate::model::emote_id::EmoteId;
+use crate::model::player_session_messages::{ChatMessage, CheckWordExisting, DamagePlayer, DiceRollResult, EmoteMessage, GameOver, GameState, HealPlayer, LobbyState, NextTurn, PlayerDead, PlayerLeft, PlayerResult, PlayerRolledDice, RoomClosed, RoomSummary, SpellEffect, StartPreparationTime, StatusEffectsUpdated, TakeDamage, TeammateRack, TilesRemaining, WordCreated, WordValidated};
+use crate::model::room_rules::RoomRules;
+use crate::model::status_effect::{StatusEffect, StatusEffectKind};
+use crate::model::tile_modifier::TileModifier;
+use crate::model::tournament_manager_messages::TournamentMatchFinished;
+use crate::model::user::{PublicUser, User};
+use crate::model::ws_response::WsResponse;
+use crate::util::constants::{FREEZE_TURNS, HEAL_AMOUNT, LOBBY_COUNTDOWN_SECONDS, MIN_PLAYERS_PER_ROOM, POISON_DAMAGE, POISON_TURNS, REGENERATION_AMOUNT, REGENERATION_TURNS, SHIELD_AMOUNT, SHIELD_TURNS, SILENCE_MIN_LETTER_VALUE, SILENCE_TURNS, TEAMS_COUNT};
+use crate::ws::dice::DieRoll;
+use crate::ws::letters::{get_tiles_value, take_used_letters};
+use crate::ws::bot_session::BotSession;
 use crate::ws::player_session::PlayerSession;
+use crate::ws::rating::calculate_ratings;
+use crate::ws::session_addr::SessionAddr;
+use crate::ws::match_log::MatchLog;
+use crate::ws::achievement_engine::AchievementEngine;
+use crate::ws::player_match_stats::PlayerMatchStats;
+use crate::ws::tile_bag::TileBag;
+
+// Match records are stored in Mongo, which has no unsigned 64-bit integer type.
+const MAX_SEED: u64 = i64::MAX as u64;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum RoomState {
+    Waiting,
+    InProgress,
+    Finished,
+}
+
+pub struct TournamentMatchRef {
+    pub tournament_id: String,
+    pub match_id: usize,
+    pub user_ids: Vec<String>,
+    pub result_recipient: Recipient<TournamentMatchFinished>,
+}
+
+struct PendingRoll {
+    player_index: usize,
+    word: String,
+    target_index: usize,
+}
 
 pub struct Room {
+    pub id: String,
+    pub code: Option<String>,
     pub users: Vec<User>,
     pub next_turn_timeout: Option<SpawnHandle>,
-    pub sessions: Vec<Addr<PlayerSession>>,
+    pub lobby_countdown: Option<SpawnHandle>,
+    pub ready_check_timeout: Option<SpawnHandle>,
+    pub sessions: Vec<SessionAddr>,
+    spectators: Vec<Addr<PlayerSession>>,
+    health: Vec<u32>,
+    letters: Vec<Vec<Letter>>,
+    status_effects: Vec<Vec<StatusEffect>>,
+    match_stats: Vec<PlayerMatchStats>,
+    achievement_engine: AchievementEngine,
+    ready: Vec<bool>,
+    connected: Vec<bool>,
+    disconnect_timeouts: Vec<Option<SpawnHandle>>,
     turn_of_player_index: u32,
-    max_players: usize,
-    game_started: bool,
+    last_team_turns: Vec<Option<usize>>,
+    rules: RoomRules,
+    tile_bag: TileBag,
+    seed: u64,
+    rng: ChaCha8Rng,
+    match_log: MatchLog,
+    pending_roll: Option<PendingRoll>,
+    checking_word: Option<usize>,
+    turn_started_at: Option<Instant>,
+    host_id: Option<String>,
+    ranked: bool,
+    practice: bool,
+    tournament_match: Option<TournamentMatchRef>,
+    created_at: Instant,
+    start_requested: bool,
+    countdown_started_at: Option<Instant>,
+    state: RoomState,
+    turns: u32,
+    started_at: Option<Instant>,
+    eliminations: Vec<usize>,
+    result: Option<GameOver>,
 }
 
 impl Room {
-    pub fn new(max_players: usize) -> Room {
+    pub fn new(rules: RoomRules) -> Room {
+        Room::with_seed(rules, rand::thread_rng().gen_range(0..=MAX_SEED))
+    }
+
+    pub fn with_seed(rules: RoomRules, seed: u64) -> Room {
         Room {
+            id: uuid::Uuid::new_v4().to_string(),
+            code: None,
             users: Vec::new(),
             next_turn_timeout: None,
+            lobby_countdown: None,
+            ready_check_timeout: None,
             sessions: Vec::new(),
+            spectators: Vec::new(),
+            health: Vec::new(),
+            letters: Vec::new(),
+            status_effects: Vec::new(),
+            match_stats: Vec::new(),
+            achievement_engine: AchievementEngine::default(),
+            ready: Vec::new(),
+            connected: Vec::new(),
+            disconnect_timeouts: Vec::new(),
             turn_of_player_index: 0,
-            max_players,
-            game_started: false,
+            last_team_turns: vec![None; TEAMS_COUNT],
+            tile_bag: TileBag::new(&rules),
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+            match_log: MatchLog::new(),
+            pending_roll: None,
+            checking_word: None,
+            turn_started_at: None,
+            rules,
+            host_id: None,
+            ranked: false,
+            practice: false,
+            tournament_match: None,
+            created_at: Instant::now(),
+            start_requested: false,
+            countdown_started_at: None,
+            state: RoomState::Waiting,
+            turns: 0,
+            started_at: None,
+            eliminations: Vec::new(),
+            result: None,
         }
     }
 
-    pub fn is_player_turn(&self, player_session_addr: Addr<PlayerSession>) -> bool {
+    pub fn new_private(rules: RoomRules, code: String, host: &User) -> Room {
+        let mut room = Room::new(rules);
+        room.code = Some(code);
+        room.host_id = Some(host.id.clone());
+        room
+    }
+
+    pub fn new_tournament(rules: RoomRules, code: String, tournament_match: TournamentMatchRef) -> Room {
+        let mut room = Room::new(rules);
+        room.code = Some(code);
+        room.tournament_match = Some(tournament_match);
+        room
+    }
+
+    pub fn new_ranked(rules: RoomRules) -> Room {
+        let mut room = Room::new(rules);
+        room.ranked = true;
+        room
+    }
+
+    pub fn new_practice(rules: RoomRules) -> Room {
+        let mut room = Room::new(rules);
+        room.practice = true;
+        room
+    }
+
+    pub fn is_open_for_matchmaking(&self) -> bool {
+        !self.is_private() && !self.ranked && !self.practice && !self.is_full()
+    }
+
+    pub fn should_fill_with_bots(&self, bot_fill_timeout: Duration) -> bool {
+        self.is_open_for_matchmaking()
+            && self.rules.language == Language::default()
+            && !self.is_empty()
+            && self.created_at.elapsed() >= bot_fill_timeout
+    }
+
+    pub fn can_join(&self, user: &User) -> bool {
+        !self.is_full() && self.tournament_match.as_ref()
+            .is_none_or(|tournament_match| tournament_match.user_ids.contains(&user.id))
+    }
+
+    pub fn report_tournament_result(&self) {
+        if let (Some(tournament_match), Some(result)) = (&self.tournament_match, &self.result) {
+            tournament_match.result_recipient.do_send(TournamentMatchFinished {
+                tournament_id: tournament_match.tournament_id.clone(),
+                match_id: tournament_match.match_id,
+                winner: result.winner.clone(),
+            });
+        }
+    }
+
+    /// Ends a tournament match that never filled up, whoever showed up wins by walkover.
+    pub fn award_walkover(&mut self) {
+        let winner = self.users.first().cloned();
+        let players = self.users.iter()
+            .map(|user| PlayerResult {
+                user: user.clone(),
+                health: self.rules.starting_health,
+                place: 1,
+                rating: None,
+                team: None,
+            })
+            .collect();
+        self.broadcast(GameOver {
+            winner: winner.clone(),
+            players,
+            turns: 0,
+            duration_seconds: 0,
+            seed: self.seed,
+            winning_team: None,
+        });
+        if let Some(tournament_match) = &self.tournament_match {
+            tournament_match.result_recipient.do_send(TournamentMatchFinished {
+                tournament_id: tournament_match.tournament_id.clone(),
+                match_id: tournament_match.match_id,
+                winner,
+            });
+        }
+    }
+
+    /// Bots draw from their own generator seeded per seat, so games with bots replay from the room seed too.
+    pub fn bot_seed(&self) -> u64 {
+        self.seed.wrapping_add(self.sessions.len() as u64 + 1)
+    }
+
+    pub fn free_seats(&self) -> usize {
+        self.rules.max_players.saturating_sub(self.sessions.len())
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.code.is_some()
+    }
+
+    pub fn rules(&self) -> &RoomRules {
+        &self.rules
+    }
+
+    pub fn is_ranked(&self) -> bool {
+        self.ranked
+    }
+
+    pub fn result(&self) -> Option<&GameOver> {
+        self.result.as_ref()
+    }
+
+    pub fn can_start_early(&self, user: &User) -> bool {
+        self.host_id.as_ref() == Some(&user.id)
+            && !self.has_started()
+            && self.has_enough_players()
+    }
+
+    fn has_enough_players(&self) -> bool {
+        if self.rules.team_mode {
+            return self.sessions.len() == self.rules.max_players;
+        }
+        self.sessions.len() >= MIN_PLAYERS_PER_ROOM
+    }
+
+    pub fn request_start(&mut self) {
+        self.start_requested = true;
+    }
+
+    pub fn set_ready(&mut self, user: &User, ready: bool) {
+        if let Some(player_index) = self.user_index(user) {
+            self.ready[player_index] = ready;
+        }
+    }
+
+    pub fn is_awaiting_ready_check(&self) -> bool {
+        !self.has_started()
+            && (self.sessions.len() == self.rules.max_players || self.start_requested)
+            && self.has_enough_players()
+    }
+
+    pub fn is_everyone_ready(&self) -> bool {
+        self.ready.iter().all(|&ready| ready)
+    }
+
+    pub fn start_countdown(&mut self, lobby_countdown: SpawnHandle) {
+        self.lobby_countdown = Some(lobby_countdown);
+        self.countdown_started_at = Some(Instant::now());
+    }
+
+    pub fn take_countdown(&mut self) -> Option<SpawnHandle> {
+        self.countdown_started_at = None;
+        self.lobby_countdown.take()
+    }
+
+    pub fn remove_unready_players(&mut self) -> Vec<SessionAddr> {
+        let unready_users: Vec<User> = self.users.iter().zip(self.ready.iter())
+            .filter(|(_, &ready)| !ready)
+            .map(|(user, _)| user.clone())
+            .collect();
+
+        let mut removed_sessions = Vec::new();
+        for user in unready_users {
+            if let Some(player_index) = self.user_index(&user) {
+                removed_sessions.push(self.sessions[player_index].clone());
+            }
+            self.remove_player(&user);
+        }
+        self.start_requested = false;
+        removed_sessions
+    }
+
+    pub fn close_bots(&self) {
+        for session in &self.sessions {
+            if let SessionAddr::Bot(bot_session_addr) = session {
+                bot_session_addr.do_send(RoomClosed);
+            }
+        }
+    }
+
+    pub fn take_players(&mut self) -> Vec<(User, SessionAddr)> {
+        let players = self.users.drain(..).zip(self.sessions.drain(..)).collect();
+        self.health.clear();
+        self.letters.clear();
+        self.status_effects.clear();
+        self.match_stats.clear();
+        self.ready.clear();
+        self.connected.clear();
+        self.disconnect_timeouts.clear();
+        players
+    }
+
+    pub fn take_unlocked_achievements(&mut self) -> Vec<(User, Addr<PlayerSession>, AchievementId)> {
+        self.achievement_engine.take_unlocked().into_iter()
+            .filter_map(|(player_index, achievement_id)| match self.sessions.get(player_index) {
+                Some(SessionAddr::Player(player_session_addr)) => {
+                    Some((self.users[player_index].clone(), player_session_addr.clone(), achievement_id))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn broadcast_lobby_state(&mut self) {
+        self.broadcast(self.lobby_state());
+    }
+
+    fn lobby_state(&self) -> LobbyState {
+        let countdown_seconds = self.countdown_started_at.map(|started_at| {
+            LOBBY_COUNTDOWN_SECONDS.saturating_sub(started_at.elapsed().as_secs())
+        });
+        LobbyState {
+            users: self.users.clone(),
+            ready: self.ready.clone(),
+            countdown_seconds,
+        }
+    }
+
+    pub fn is_player_turn(&self, player_session_addr: SessionAddr) -> bool {
         let player_index = self.sessions.iter()
             .position(|session| &player_session_addr == session);
         let player_index = match player_index {
@@ -41,45 +367,620 @@ impl Room {
 
     pub fn increase_turn_index(&mut self) {
         self.next_turn_timeout = None;
-        let max_player = u32::try_from(self.max_players.clone()).unwrap_or(0);
-        if self.turn_of_player_index.clone() < max_player - 1 {
-            self.turn_of_player_index = self.turn_of_player_index.clone() + 1;
+        self.pending_roll = None;
+        self.checking_word = None;
+        if self.is_finished() {
+            return;
+        }
+        if self.is_out_of_tiles() {
+            self.finish_game();
+            return;
+        }
+
+        let current_index = self.turn_of_player_index as usize;
+        self.expire_status_effects(current_index);
+        let players_count = self.sessions.len();
+        let turn_order: Vec<usize> = if self.rules.team_mode {
+            if let Some(team) = self.team(current_index) {
+                self.last_team_turns[team] = Some(current_index);
+            }
+            team_turn_order(current_index, players_count, &self.last_team_turns)
         } else {
-            self.turn_of_player_index = 0;
+            (1..=players_count)
+                .map(|offset| (current_index + offset) % players_count)
+                .collect()
+        };
+        for player_index in turn_order {
+            self.turn_of_player_index = u32::try_from(player_index).unwrap_or(0);
+            if !self.can_take_turn(player_index) {
+                continue;
+            }
+            if let Some(team) = self.team(player_index) {
+                self.last_team_turns[team] = Some(player_index);
+            }
+            let frozen = self.tick_status_effects(player_index);
+            if self.is_finished() {
+                return;
+            }
+            if !self.is_alive(player_index) || frozen {
+                self.expire_status_effects(player_index);
+                continue;
+            }
+            break;
         }
+        self.turns += 1;
+        self.turn_started_at = Some(Instant::now());
 
-        for player in &self.sessions {
-            let _ = player.do_send(NextTurn {
-                player_index: usize::try_from(self.turn_of_player_index.clone()).unwrap_or(0),
-                seconds: TURN_SECONDS,
-            });
+        self.broadcast(NextTurn {
+            player_index: usize::try_from(self.turn_of_player_index).unwrap_or(0),
+            seconds: self.rules.turn_seconds,
+        });
+    }
+
+    fn tick_status_effects(&mut self, player_index: usize) -> bool {
+        let effects = &mut self.status_effects[player_index];
+        if effects.is_empty() {
+            return false;
         }
+        let periodic_amount = |kind| effects.iter()
+            .filter(|effect: &&StatusEffect| effect.kind == kind)
+            .map(|effect| effect.amount)
+            .sum::<u32>();
+        let regeneration = periodic_amount(StatusEffectKind::Regeneration);
+        let poison = periodic_amount(StatusEffectKind::Poison);
+        let frozen = effects.iter().any(|effect| effect.kind == StatusEffectKind::Freeze);
+        for effect in effects.iter_mut() {
+            effect.turns = effect.turns.saturating_sub(1);
+        }
+        self.broadcast_status_effects(player_index);
+
+        if regeneration > 0 {
+            self.heal_player(regeneration, player_index);
+        }
+        if poison > 0 {
+            self.on_damage_player(poison, player_index);
+        }
+        frozen
+    }
+
+    /// Drops the effects whose last turn was the one that just ended.
+    fn expire_status_effects(&mut self, player_index: usize) {
+        let effects = &mut self.status_effects[player_index];
+        if effects.iter().all(|effect| effect.turns > 0) {
+            return;
+        }
+        effects.retain(|effect| effect.turns > 0);
+        self.broadcast_status_effects(player_index);
+    }
+
+    fn add_status_effect(&mut self, player_index: usize, status_effect: StatusEffect) {
+        let effects = &mut self.status_effects[player_index];
+        match effects.iter_mut().find(|effect| effect.kind == status_effect.kind) {
+            Some(effect) => effect.merge(&status_effect),
+            None => effects.push(status_effect),
+        }
+        self.broadcast_status_effects(player_index);
+    }
+
+    fn has_status_effect(&self, player_index: usize, kind: StatusEffectKind) -> bool {
+        self.status_effects[player_index].iter().any(|effect| effect.kind == kind)
+    }
+
+    fn broadcast_status_effects(&mut self, player_index: usize) {
+        self.broadcast(StatusEffectsUpdated {
+            player_index,
+            effects: self.status_effects[player_index].clone(),
+        });
+    }
+
+    pub fn silenced_letters(&self, player_session_addr: &SessionAddr, word: &str) -> Vec<char> {
+        let player_index = self.sessions.iter().position(|session| player_session_addr == session);
+        let player_index = match player_index {
+            Some(index) if self.has_status_effect(index, StatusEffectKind::Silence) => index,
+            _ => return Vec::new(),
+        };
+        let mut rack = self.letters[player_index].clone();
+        take_used_letters(&mut rack, word).iter()
+            .filter(|letter| letter.value >= SILENCE_MIN_LETTER_VALUE)
+            .map(|letter| letter.letter)
+            .collect()
     }
 
-    pub fn add_player(&mut self, user: User, player_session_addr: Addr<PlayerSession>) {
+    pub fn add_player(&mut self, user: User, player_session_addr: SessionAddr) {
         let is_player_already_in_room = self.sessions.contains(&player_session_addr);
-        if self.sessions.len() < self.max_players && !is_player_already_in_room {
+        if self.sessions.len() < self.rules.max_players && !is_player_already_in_room {
             self.sessions.push(player_session_addr);
             self.users.push(user);
+            self.health.push(self.rules.starting_health);
+            self.letters.push(Vec::new());
+            self.status_effects.push(Vec::new());
+            self.match_stats.push(PlayerMatchStats::default());
+            self.ready.push(false);
+            self.connected.push(true);
+            self.disconnect_timeouts.push(None);
+        }
+    }
+
+    pub fn add_bot(&mut self, user: User, bot_session_addr: Addr<BotSession>) {
+        self.add_player(user.clone(), bot_session_addr.into());
+        self.set_ready(&user, true);
+    }
+
+    pub fn remove_player(&mut self, user: &User) {
+        if let Some(player_index) = self.user_index(user) {
+            self.users.remove(player_index);
+            self.sessions.remove(player_index);
+            self.health.remove(player_index);
+            self.letters.remove(player_index);
+            self.status_effects.remove(player_index);
+            self.match_stats.remove(player_index);
+            self.ready.remove(player_index);
+            self.connected.remove(player_index);
+            self.disconnect_timeouts.remove(player_index);
+
+            self.broadcast(PlayerLeft {
+                player_index,
+            });
         }
     }
 
     pub fn is_full(&self) -> bool {
-        self.game_started.clone() || self.sessions.len() == self.max_players
+        self.state != RoomState::Waiting || self.sessions.len() == self.rules.max_players
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state == RoomState::Finished
+    }
+
+    pub fn has_started(&self) -> bool {
+        self.state != RoomState::Waiting
+    }
+
+    /// Time left in the current turn, counting any preparation time that has not passed yet.
+    pub fn remaining_turn_time(&self) -> Duration {
+        let turn_duration = Duration::from_secs(self.rules.turn_seconds);
+        match self.turn_started_at {
+            Some(turn_started_at) => (turn_started_at + turn_duration).saturating_duration_since(Instant::now()),
+            None => turn_duration,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.sessions.iter().any(|session| session.is_player())
+    }
+
+    pub fn is_abandoned(&self) -> bool {
+        self.is_empty() && self.tournament_match.is_none()
     }
 
     pub fn start_game(&mut self) {
-        self.game_started = true;
-        for player in &self.sessions {
-            let _ = player.do_send(StartPreparationTime {
-                seconds: PREPARATION_TIME_SECONDS,
+        self.countdown_started_at = None;
+        self.state = RoomState::InProgress;
+        self.started_at = Some(Instant::now());
+        self.turn_started_at = Some(Instant::now() + Duration::from_secs(self.rules.preparation_seconds));
+        self.achievement_engine = AchievementEngine::new(self.sessions.len());
+        self.last_team_turns = vec![None; TEAMS_COUNT];
+        println!("Room {} started with seed {}", self.id, self.seed);
+        for player_index in 0..self.sessions.len() {
+            self.letters[player_index] = self.tile_bag.fill_rack(&mut self.rng, Vec::new(), &self.rules);
+        }
+        for player_index in 0..self.sessions.len() {
+            let start_preparation_time = StartPreparationTime {
+                seconds: self.rules.preparation_seconds,
+                rules: self.rules.clone(),
                 users: self.users.clone(),
-                letters: get_random_letters(MAX_LETTERS),
+                letters: self.letters[player_index].clone(),
+                teams: self.teams(),
+                teammate_racks: self.teammate_racks(player_index),
+            };
+            self.record(Some(player_index), WsResponse::StartPreparationTime(start_preparation_time.clone()));
+            self.sessions[player_index].do_send(start_preparation_time);
+        }
+        self.broadcast_tiles_remaining();
+    }
+
+    pub fn contains_user(&self, user: &User) -> bool {
+        self.user_index(user).is_some()
+    }
+
+    pub fn reconnect_player(&mut self, user: &User, player_session_addr: Addr<PlayerSession>) -> Option<SpawnHandle> {
+        let player_index = self.user_index(user)?;
+        self.sessions[player_index] = player_session_addr.clone().into();
+        self.connected[player_index] = true;
+
+        match self.state {
+            RoomState::Waiting => player_session_addr.do_send(self.lobby_state()),
+            RoomState::InProgress => player_session_addr.do_send(self.game_state(Some(player_index))),
+            RoomState::Finished => {}
+        }
+        self.disconnect_timeouts[player_index].take()
+    }
+
+    pub fn disconnect_player(&mut self, user: &User, forfeit_timeout: SpawnHandle) {
+        if let Some(player_index) = self.user_index(user) {
+            self.connected[player_index] = false;
+            self.disconnect_timeouts[player_index] = Some(forfeit_timeout);
+        }
+    }
+
+    pub fn forfeit_player(&mut self, user: &User) {
+        let player_index = match self.user_index(user) {
+            Some(index) => index,
+            None => return,
+        };
+        self.disconnect_timeouts[player_index] = None;
+        if self.connected[player_index] || !self.is_alive(player_index) {
+            return;
+        }
+        self.health[player_index] = 0;
+        self.eliminations.push(player_index);
+
+        self.broadcast(PlayerLeft {
+            player_index,
+        });
+        self.check_game_over();
+    }
+
+    pub fn take_disconnect_timeouts(&mut self) -> Vec<SpawnHandle> {
+        self.disconnect_timeouts.iter_mut()
+            .filter_map(|timeout| timeout.take())
+            .collect()
+    }
+
+    fn refill_rack(&mut self, player_index: usize, rack: Vec<Letter>, used_letters: Vec<Letter>) -> Vec<Letter> {
+        // Returned tiles go back only after the refill so a player cannot redraw them at once.
+        let rack = self.tile_bag.fill_rack(&mut self.rng, rack, &self.rules);
+        if self.rules.return_used_tiles {
+            self.tile_bag.return_tiles(used_letters);
+        }
+        self.letters[player_index] = rack.clone();
+        self.send_rack_to_teammates(player_index);
+        self.broadcast_tiles_remaining();
+        rack
+    }
+
+    fn team(&self, player_index: usize) -> Option<usize> {
+        self.rules.team_mode.then_some(player_index % TEAMS_COUNT)
+    }
+
+    fn teams(&self) -> Option<Vec<usize>> {
+        self.rules.team_mode.then(|| (0..self.users.len()).map(|index| index % TEAMS_COUNT).collect())
+    }
+
+    fn are_teammates(&self, player_index: usize, other_index: usize) -> bool {
+        player_index != other_index
+            && self.team(player_index).is_some()
+            && self.team(player_index) == self.team(other_index)
+    }
+
+    fn teammate_racks(&self, player_index: usize) -> Vec<TeammateRack> {
+        (0..self.sessions.len())
+            .filter(|&index| self.are_teammates(player_index, index))
+            .map(|index| TeammateRack {
+                player_index: index,
+                letters: self.letters[index].clone(),
+            })
+            .collect()
+    }
+
+    fn send_rack_to_teammates(&mut self, player_index: usize) {
+        let teammate_rack = TeammateRack {
+            player_index,
+            letters: self.letters[player_index].clone(),
+        };
+        for teammate_index in 0..self.sessions.len() {
+            if self.are_teammates(player_index, teammate_index) {
+                self.record(Some(teammate_index), WsResponse::TeammateRack(teammate_rack.clone()));
+                self.sessions[teammate_index].do_send(teammate_rack.clone());
+            }
+        }
+    }
+
+    pub fn is_waiting_for_word(&self) -> bool {
+        self.checking_word.is_some() || self.pending_roll.is_some()
+    }
+
+    pub fn on_word_check_failed(&mut self, user: &User) {
+        if self.checking_word.is_some() && self.checking_word == self.user_index(user) {
+            self.checking_word = None;
+        }
+    }
+
+    pub fn on_word_validated(&mut self, user: &User, word: String, target_index: Option<usize>) {
+        let player_index = match self.user_index(user) {
+            Some(index) if self.checking_word == Some(index) && self.turn_of_player_index as usize == index => index,
+            _ => return,
+        };
+        self.checking_word = None;
+
+        self.broadcast(WordValidated {
+            player_index,
+            word: word.clone(),
+            valid: target_index.is_some(),
+        });
+        if target_index.is_some() {
+            self.match_stats[player_index].record_accepted_word(&word);
+        }
+        self.pending_roll = target_index.map(|target_index| PendingRoll {
+            player_index,
+            word,
+            target_index,
+        });
+    }
+
+    pub fn roll_dice(&mut self, user: &User) {
+        let pending_roll = match self.pending_roll.take() {
+            Some(pending_roll) if Some(pending_roll.player_index) == self.user_index(user) => pending_roll,
+            other => {
+                self.pending_roll = other;
+                return;
+            }
+        };
+
+        let PendingRoll { player_index, word, target_index } = pending_roll;
+        let mut rack = self.letters[player_index].clone();
+        let used_letters = take_used_letters(&mut rack, &word);
+        let word_damage = self.rules.apply_damage_multiplier(get_tiles_value(&used_letters));
+        let die_roll = DieRoll::roll(&mut self.rng);
+        let damage = die_roll.apply(word_damage);
+        let modifiers: Vec<TileModifier> = used_letters.iter().filter_map(|letter| letter.modifier).collect();
+        let new_letters = self.refill_rack(player_index, rack, used_letters);
+
+        let dice_roll_result = DiceRollResult {
+            target_index,
+            face: die_roll.face,
+            multiplier: die_roll.multiplier,
+            damage,
+            new_letters,
+        };
+        self.record(Some(player_index), WsResponse::DiceRolledResponse(dice_roll_result.clone()));
+        self.sessions[player_index].do_send(dice_roll_result);
+        self.broadcast(PlayerRolledDice {
+            player_index,
+            target_index,
+            face: die_roll.face,
+            multiplier: die_roll.multiplier,
+            damage,
+        });
+
+        self.apply_caster_effects(player_index, &modifiers);
+        let damage_dealt = self.on_damage_player(damage, target_index);
+        self.match_stats[player_index].record_damage(&word, damage_dealt);
+        if damage > 0 && self.is_alive(target_index) {
+            self.apply_target_effects(player_index, target_index, &modifiers);
+        }
+        self.increase_turn_index();
+    }
+
+    fn apply_caster_effects(&mut self, caster_index: usize, modifiers: &[TileModifier]) {
+        for &modifier in modifiers {
+            let status_effect = match modifier {
+                TileModifier::Shield => Some(StatusEffect {
+                    kind: StatusEffectKind::Shield,
+                    amount: SHIELD_AMOUNT,
+                    turns: SHIELD_TURNS,
+                }),
+                TileModifier::Regeneration => Some(StatusEffect {
+                    kind: StatusEffectKind::Regeneration,
+                    amount: REGENERATION_AMOUNT,
+                    turns: REGENERATION_TURNS,
+                }),
+                _ => None,
+            };
+            let amount = match modifier {
+                TileModifier::DoubleLetter => 2,
+                TileModifier::TripleWord => 3,
+                TileModifier::Heal => HEAL_AMOUNT,
+                TileModifier::Shield => SHIELD_AMOUNT,
+                TileModifier::Regeneration => REGENERATION_AMOUNT,
+                TileModifier::Poison | TileModifier::Freeze | TileModifier::Silence => continue,
+            };
+            self.broadcast(SpellEffect {
+                caster_index,
+                target_index: caster_index,
+                modifier,
+                amount,
+                turns: status_effect.as_ref().map_or(0, |status_effect| status_effect.turns),
             });
+            if modifier == TileModifier::Heal {
+                self.heal_player(HEAL_AMOUNT, caster_index);
+            }
+            if let Some(status_effect) = status_effect {
+                self.add_status_effect(caster_index, status_effect);
+            }
         }
     }
 
-    pub fn on_word_created(&self, word: String, player_session_addr: Addr<PlayerSession>) {
+    fn apply_target_effects(&mut self, caster_index: usize, target_index: usize, modifiers: &[TileModifier]) {
+        for &modifier in modifiers {
+            let status_effect = match modifier {
+                TileModifier::Poison => StatusEffect {
+                    kind: StatusEffectKind::Poison,
+                    amount: POISON_DAMAGE,
+                    turns: POISON_TURNS,
+                },
+                TileModifier::Freeze => StatusEffect {
+                    kind: StatusEffectKind::Freeze,
+                    amount: 0,
+                    turns: FREEZE_TURNS,
+                },
+                TileModifier::Silence => StatusEffect {
+                    kind: StatusEffectKind::Silence,
+                    amount: SILENCE_MIN_LETTER_VALUE,
+                    turns: SILENCE_TURNS,
+                },
+                _ => continue,
+            };
+            self.broadcast(SpellEffect {
+                caster_index,
+                target_index,
+                modifier,
+                amount: status_effect.amount,
+                turns: status_effect.turns,
+            });
+            self.add_status_effect(target_index, status_effect);
+        }
+    }
+
+    pub fn match_record(&self) -> MatchRecord {
+        MatchRecord {
+            id: self.id.clone(),
+            seed: self.seed,
+            rules: self.rules.clone(),
+            users: self.users.clone(),
+            created_at: self.match_log.created_at(),
+            events: self.match_log.events().to_vec(),
+            players: self.player_summaries(),
+            ranked: self.ranked,
+            practice: self.practice,
+        }
+    }
+
+    fn player_summaries(&self) -> Vec<MatchPlayerSummary> {
+        let result = match &self.result {
+            Some(result) => result,
+            None => return Vec::new(),
+        };
+        result.players.iter().enumerate()
+            .map(|(index, player)| {
+                let won = match result.winning_team {
+                    Some(winning_team) => player.team == Some(winning_team),
+                    None => result.winner.as_ref().is_some_and(|winner| winner.id == player.user.id),
+                };
+                let stats = &self.match_stats[index];
+                MatchPlayerSummary {
+                    user_id: player.user.id.clone(),
+                    name: player.user.name.clone(),
+                    bot: matches!(self.sessions[index], SessionAddr::Bot(_)),
+                    won,
+                    place: player.place,
+                    rating: player.rating,
+                    damage_dealt: stats.damage_dealt,
+                    longest_word: stats.longest_word.clone(),
+                    longest_word_length: stats.longest_word.chars().count(),
+                    words_submitted: stats.words_submitted,
+                    words_accepted: stats.words_accepted,
+                    highest_damage_word: stats.highest_damage_word.clone().map(|(word, damage)| WordDamage { word, damage }),
+                    letters_used: stats.letters_used.iter()
+                        .map(|(letter, &count)| (letter.to_string(), count))
+                        .collect(),
+                    turn_millis: stats.turn_millis,
+                    timed_turns: stats.timed_turns,
+                }
+            })
+            .collect()
+    }
+
+    fn broadcast_tiles_remaining(&mut self) {
+        self.broadcast(TilesRemaining {
+            count: self.tile_bag.remaining(),
+        });
+    }
+
+    fn is_out_of_tiles(&self) -> bool {
+        self.tile_bag.remaining() == 0
+            && (0..self.sessions.len())
+                .filter(|&index| self.is_alive(index))
+                .all(|index| self.letters[index].is_empty())
+    }
+
+    fn user_index(&self, user: &User) -> Option<usize> {
+        self.users.iter().position(|u| u.id == user.id)
+    }
+
+    pub fn add_spectator(&mut self, spectator_session_addr: Addr<PlayerSession>) {
+        if !self.spectators.contains(&spectator_session_addr) {
+            self.spectators.push(spectator_session_addr.clone());
+        }
+        spectator_session_addr.do_send(self.game_state(None));
+    }
+
+    pub fn remove_spectator(&mut self, spectator_session_addr: &Addr<PlayerSession>) {
+        self.spectators.retain(|spectator| spectator != spectator_session_addr);
+    }
+
+    pub fn summary(&self) -> RoomSummary {
+        RoomSummary {
+            id: self.id.clone(),
+            users: self.public_users(),
+            health: self.health.clone(),
+            status_effects: self.status_effects.clone(),
+            turns: self.turns,
+            spectators: self.spectators.len(),
+        }
+    }
+
+    pub fn on_chat(&mut self, user: &User, message: String) {
+        self.broadcast(ChatMessage {
+            user_id: user.id.clone(),
+            name: user.name.clone(),
+            message,
+        });
+    }
+
+    pub fn on_emote(&mut self, user: &User, emote: EmoteId) {
+        self.broadcast(EmoteMessage {
+            user_id: user.id.clone(),
+            name: user.name.clone(),
+            emote,
+        });
+    }
+
+    fn broadcast<M>(&mut self, msg: M)
+        where
+            M: Message<Result = ()> + Clone + Send + Into<WsResponse> + 'static,
+            PlayerSession: Handler<M>,
+            BotSession: Handler<M>,
+            <PlayerSession as Actor>::Context: ToEnvelope<PlayerSession, M>,
+            <BotSession as Actor>::Context: ToEnvelope<BotSession, M>,
+    {
+        for player in &self.sessions {
+            player.do_send(msg.clone());
+        }
+        for spectator in &self.spectators {
+            spectator.do_send(msg.clone());
+        }
+        self.record(None, msg.into());
+    }
+
+    fn record(&mut self, player_index: Option<usize>, event: WsResponse) {
+        self.achievement_engine.on_event(player_index, &event);
+        self.match_log.record(player_index, event);
+    }
+
+    fn public_users(&self) -> Vec<PublicUser> {
+        self.users.iter().map(PublicUser::from).collect()
+    }
+
+    fn game_state(&self, player_index: Option<usize>) -> GameState {
+        GameState {
+            room_id: self.id.clone(),
+            users: self.public_users(),
+            health: self.health.clone(),
+            letters: player_index.map(|index| self.letters[index].clone()).unwrap_or_default(),
+            player_index,
+            turn_player_index: usize::try_from(self.turn_of_player_index).unwrap_or(0),
+            turns: self.turns,
+            rules: self.rules.clone(),
+            tiles_remaining: self.tile_bag.remaining(),
+            status_effects: self.status_effects.clone(),
+            teams: self.teams(),
+            teammate_racks: player_index.map(|index| self.teammate_racks(index)).unwrap_or_default(),
+        }
+    }
+
+    pub fn is_valid_target(&self, player_session_addr: &SessionAddr, target_user_id: &str) -> bool {
+        let player_index = self.sessions.iter().position(|session| player_session_addr == session);
+        let target_index = self.users.iter().position(|user| user.id == target_user_id);
+        match (player_index, target_index) {
+            (Some(player_index), Some(target_index)) => player_index != target_index
+                && !self.are_teammates(player_index, target_index)
+                && self.is_alive(target_index),
+            _ => false,
+        }
+    }
+
+    pub fn on_word_created(&mut self, word: String, player_session_addr: SessionAddr, target_user_id: Option<String>) {
         let player_index = self.sessions.iter()
             .position(|session| &player_session_addr == session);
 
@@ -88,46 +989,392 @@ impl Room {
             None => return,
         };
 
-        for player in &self.sessions {
-            let word = word.clone();
-            let player_index = player_index.clone();
+        let turn_duration = self.turn_started_at
+            .map(|turn_started_at| Instant::now().saturating_duration_since(turn_started_at))
+            .unwrap_or_default();
+        self.match_stats[player_index].record_submission(turn_duration);
+        self.checking_word = Some(player_index);
 
-            let _ = player.do_send(WordCreated {
-                player_index,
-                word,
-            });
-        }
+        let chosen_target_index = target_user_id
+            .and_then(|target_user_id| self.users.iter().position(|user| user.id == target_user_id));
+        let target_index = match chosen_target_index {
+            Some(target_index) => Some(target_index),
+            None => {
+                let living_opponent_indices: Vec<usize> = (0..self.sessions.len())
+                    .filter(|&index| index != player_index && !self.are_teammates(player_index, index) && self.is_alive(index))
+                    .collect();
+                living_opponent_indices.choose(&mut self.rng).copied()
+            }
+        };
 
-        self.sessions[player_index.clone()].do_send(CheckWordExisting {
+        self.broadcast(WordCreated {
             player_index,
+            word: word.clone(),
+            target_index,
+        });
+
+        self.sessions[player_index].do_send(CheckWordExisting {
+            player_index,
+            target_index,
             word,
         })
     }
 
-    pub fn on_damage_player(&self, damage: u32, player_index: usize) {
-        self.sessions[player_index.clone()].do_send(TakeDamage {
-            damage: damage.clone(),
-            player_index: player_index.clone(),
+    pub fn on_damage_player(&mut self, damage: u32, player_index: usize) -> u32 {
+        if self.is_finished() || !self.is_alive(player_index) {
+            return 0;
+        }
+        let absorbed = self.absorb_damage(damage, player_index);
+        let damage = damage - absorbed;
+        let previous_health = self.health[player_index];
+        self.health[player_index] = previous_health.saturating_sub(damage);
+        let health = self.health[player_index];
+
+        self.sessions[player_index].do_send(TakeDamage {
+            damage,
+            absorbed,
+            health,
+            player_index,
         });
 
-        for player in &self.sessions {
-            player.do_send(DamagePlayer {
-                player_index: player_index.clone(),
-                damage: damage.clone(),
-            });
+        self.broadcast(DamagePlayer {
+            player_index,
+            damage,
+            absorbed,
+            health,
+        });
+
+        if !self.is_alive(player_index) {
+            self.on_player_dead(player_index);
         }
+        previous_health - health
     }
 
-    pub fn on_player_dead(&mut self, msg: RoomPlayerDead) {
-        if let Some(user_index) = self.users.iter().position(|u| u.clone() == msg.user) {
-            self.users.remove(user_index);
+    fn absorb_damage(&mut self, damage: u32, player_index: usize) -> u32 {
+        let shield = self.status_effects[player_index].iter_mut()
+            .find(|effect| effect.kind == StatusEffectKind::Shield);
+        let absorbed = match shield {
+            Some(shield) => {
+                let absorbed = damage.min(shield.amount);
+                shield.amount -= absorbed;
+                absorbed
+            }
+            None => return 0,
+        };
+        self.status_effects[player_index]
+            .retain(|effect| effect.kind != StatusEffectKind::Shield || effect.amount > 0);
+        self.broadcast_status_effects(player_index);
+        absorbed
+    }
+
+    fn heal_player(&mut self, amount: u32, player_index: usize) {
+        if self.is_finished() || !self.is_alive(player_index) {
+            return;
         }
-        self.sessions.remove(msg.player_index);
+        let health = self.health[player_index];
+        self.health[player_index] = (health + amount).min(self.rules.starting_health);
 
-        for player in &self.sessions {
-            player.do_send(PlayerDead {
-                player_index: msg.player_index.clone()
+        self.broadcast(HealPlayer {
+            player_index,
+            amount: self.health[player_index] - health,
+            health: self.health[player_index],
+        });
+    }
+
+    fn on_player_dead(&mut self, player_index: usize) {
+        self.eliminations.push(player_index);
+        self.broadcast(PlayerDead {
+            player_index,
+        });
+        self.check_game_over();
+    }
+
+    fn check_game_over(&mut self) {
+        if self.rules.team_mode {
+            let mut alive_teams: Vec<Option<usize>> = (0..self.sessions.len())
+                .filter(|&index| self.is_alive(index))
+                .map(|index| self.team(index))
+                .collect();
+            alive_teams.sort();
+            alive_teams.dedup();
+            if alive_teams.len() <= 1 {
+                self.finish_game();
+            }
+            return;
+        }
+        let alive_players_count = (0..self.sessions.len())
+            .filter(|&index| self.is_alive(index))
+            .count();
+        if alive_players_count <= 1 {
+            self.finish_game();
+        }
+    }
+
+    fn finish_game(&mut self) {
+        self.state = RoomState::Finished;
+
+        let winning_team = self.winning_team();
+        let winner = (0..self.sessions.len())
+            .filter(|&index| self.is_alive(index))
+            .filter(|&index| winning_team.is_none() || self.team(index) == winning_team)
+            .max_by_key(|&index| self.health[index])
+            .map(|index| self.users[index].clone());
+        let places: Vec<usize> = (0..self.users.len())
+            .map(|index| match winning_team {
+                Some(team) if self.team(index) == Some(team) => 1,
+                Some(_) => 2,
+                None => self.place(index),
+            })
+            .collect();
+        let ratings: Vec<Option<i32>> = if self.ranked {
+            let current_ratings: Vec<i32> = self.users.iter().map(|user| user.rating).collect();
+            calculate_ratings(&current_ratings, &places).into_iter().map(Some).collect()
+        } else {
+            vec![None; self.users.len()]
+        };
+        let players: Vec<PlayerResult> = (0..self.users.len())
+            .map(|index| PlayerResult {
+                user: self.users[index].clone(),
+                health: self.health[index],
+                place: places[index],
+                rating: ratings[index],
+                team: self.team(index),
             })
+            .collect();
+        let duration_seconds = match self.started_at {
+            Some(started_at) => started_at.elapsed().as_secs(),
+            None => 0,
+        };
+
+        let game_over = GameOver {
+            winner,
+            players,
+            turns: self.turns,
+            duration_seconds,
+            seed: self.seed,
+            winning_team,
+        };
+        self.broadcast(game_over.clone());
+        self.result = Some(game_over);
+    }
+
+    fn winning_team(&self) -> Option<usize> {
+        if !self.rules.team_mode {
+            return None;
+        }
+        (0..TEAMS_COUNT).max_by_key(|&team| {
+            (0..self.sessions.len())
+                .filter(|&index| self.team(index) == Some(team))
+                .map(|index| self.health[index])
+                .sum::<u32>()
+        })
+    }
+
+    /// Eliminated players place by when they fell; survivors, e.g. when the tiles run out, by health.
+    fn place(&self, player_index: usize) -> usize {
+        match self.eliminations.iter().position(|&index| index == player_index) {
+            Some(elimination) => self.users.len() - elimination,
+            None => {
+                let healthier = (0..self.users.len())
+                    .filter(|&index| self.is_alive(index) && self.health[index] > self.health[player_index])
+                    .count();
+                healthier + 1
+            }
+        }
+    }
+
+    fn is_alive(&self, player_index: usize) -> bool {
+        self.health.get(player_index).is_some_and(|&health| health > 0)
+    }
+
+    fn can_take_turn(&self, player_index: usize) -> bool {
+        self.is_alive(player_index) && self.connected[player_index]
+    }
+}
+
+/// Opponents play first, and within each team the turn passes to the teammate after the last one who acted.
+fn team_turn_order(current_index: usize, players_count: usize, last_team_turns: &[Option<usize>]) -> Vec<usize> {
+    let current_team = current_index % TEAMS_COUNT;
+    (1..=TEAMS_COUNT)
+        .map(|offset| (current_team + offset) % TEAMS_COUNT)
+        .flat_map(|team| {
+            let last_index = last_team_turns[team].unwrap_or(players_count - 1);
+            (1..=players_count)
+                .map(move |offset| (last_index + offset) % players_count)
+                .filter(move |index| index % TEAMS_COUNT == team)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use actix::{AsyncContext, Context};
+
+    use super::*;
+    use crate::util::constants::{DEFAULT_RATING, TEAM_MODE_PLAYERS};
+
+    fn next_turn(current_index: usize, alive: &[bool], last_team_turns: &mut [Option<usize>]) -> usize {
+        last_team_turns[current_index % TEAMS_COUNT] = Some(current_index);
+        let next_index = team_turn_order(current_index, alive.len(), last_team_turns)
+            .into_iter()
+            .find(|&index| alive[index])
+            .unwrap();
+        last_team_turns[next_index % TEAMS_COUNT] = Some(next_index);
+        next_index
+    }
+
+    #[test]
+    fn team_turns_alternate_between_teams_and_teammates() {
+        let alive = [true; TEAM_MODE_PLAYERS];
+        let mut last_team_turns = vec![None; TEAMS_COUNT];
+        let mut current_index = 0;
+        let mut turns = Vec::new();
+        for _ in 0..8 {
+            current_index = next_turn(current_index, &alive, &mut last_team_turns);
+            turns.push(current_index);
+        }
+        assert_eq!(turns, vec![1, 2, 3, 0, 1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn team_turns_reach_every_living_player_after_a_death() {
+        let alive = [true, false, true, true];
+        let mut last_team_turns = vec![None; TEAMS_COUNT];
+        let mut current_index = 0;
+        let mut turns = Vec::new();
+        for _ in 0..6 {
+            current_index = next_turn(current_index, &alive, &mut last_team_turns);
+            turns.push(current_index);
+        }
+        assert_eq!(turns, vec![3, 2, 3, 0, 3, 2]);
+    }
+
+    fn started_room(players_count: usize, seed: u64) -> Room {
+        let rules = RoomRules {
+            max_players: players_count,
+            ..RoomRules::default()
+        };
+        let mut room = Room::with_seed(rules, seed);
+        for index in 0..players_count {
+            let user = User {
+                id: format!("player-{}", index),
+                name: format!("Player {}", index),
+                email: String::new(),
+                photo: String::new(),
+                provider: String::new(),
+                rating: DEFAULT_RATING,
+            };
+            room.add_player(user, Context::<BotSession>::new().address().into());
+        }
+        room.start_game();
+        room
+    }
+
+    fn play_seeded_match(seed: u64) -> Vec<serde_json::Value> {
+        let mut room = started_room(3, seed);
+        for _ in 0..6 {
+            if room.is_finished() {
+                break;
+            }
+            let player_index = room.turn_of_player_index as usize;
+            let user = room.users[player_index].clone();
+            let word: String = room.letters[player_index].iter().take(3).map(|letter| letter.letter).collect();
+            room.on_word_created(word.clone(), room.sessions[player_index].clone(), None);
+            let target_index = match room.match_log.events().last().map(|event| &event.event) {
+                Some(WsResponse::WordCreated(word_created)) => word_created.target_index,
+                _ => None,
+            };
+            room.on_word_validated(&user, word, target_index);
+            room.roll_dice(&user);
+        }
+        room.match_log.events().iter()
+            .map(|event| serde_json::to_value(&event.event).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rooms_with_the_same_seed_replay_identically() {
+        let events = play_seeded_match(42);
+        let dice_rolls = events.iter().filter(|event| event["type"] == "DiceRolledResponse").count();
+        assert_eq!(dice_rolls, 6);
+        assert_eq!(events, play_seeded_match(42));
+        assert_ne!(events, play_seeded_match(43));
+    }
+
+    #[test]
+    fn shield_absorbs_damage_until_it_is_used_up() {
+        let mut room = started_room(2, 1);
+        let starting_health = room.health[1];
+        room.add_status_effect(1, StatusEffect {
+            kind: StatusEffectKind::Shield,
+            amount: SHIELD_AMOUNT,
+            turns: SHIELD_TURNS,
+        });
+
+        assert_eq!(room.on_damage_player(SHIELD_AMOUNT - 5, 1), 0);
+        assert_eq!(room.health[1], starting_health);
+        assert_eq!(room.on_damage_player(10, 1), 5);
+        assert_eq!(room.health[1], starting_health - 5);
+        assert!(!room.has_status_effect(1, StatusEffectKind::Shield));
+    }
+
+    #[test]
+    fn poison_ticks_once_per_turn_for_its_duration() {
+        let mut room = started_room(2, 1);
+        let starting_health = room.health[1];
+        room.add_status_effect(1, StatusEffect {
+            kind: StatusEffectKind::Poison,
+            amount: POISON_DAMAGE,
+            turns: POISON_TURNS,
+        });
+
+        for turn in 1..=POISON_TURNS {
+            room.tick_status_effects(1);
+            assert_eq!(room.health[1], starting_health - turn * POISON_DAMAGE);
+            room.expire_status_effects(1);
         }
+        assert!(room.status_effects[1].is_empty());
+        room.tick_status_effects(1);
+        assert_eq!(room.health[1], starting_health - POISON_TURNS * POISON_DAMAGE);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn freeze_expires_after_the_skipped_turn() {
+        let mut room = started_room(2, 1);
+        room.add_status_effect(1, StatusEffect {
+            kind: StatusEffectKind::Freeze,
+            amount: 0,
+            turns: FREEZE_TURNS,
+        });
+
+        for _ in 0..FREEZE_TURNS {
+            assert!(room.tick_status_effects(1));
+            room.expire_status_effects(1);
+        }
+        assert!(room.status_effects[1].is_empty());
+        assert!(!room.tick_status_effects(1));
+    }
+
+    #[test]
+    fn remaining_turn_time_counts_down_from_the_turn_start() {
+        let mut room = started_room(2, 1);
+        let turn_seconds = room.rules().turn_seconds;
+        room.turn_started_at = Some(Instant::now() - Duration::from_secs(10));
+        let remaining = room.remaining_turn_time();
+        assert!(remaining <= Duration::from_secs(turn_seconds - 10));
+        assert!(remaining > Duration::from_secs(turn_seconds - 11));
+
+        room.turn_started_at = Some(Instant::now() - Duration::from_secs(turn_seconds + 1));
+        assert_eq!(room.remaining_turn_time(), Duration::ZERO);
+    }
+
+    #[test]
+    fn survivors_are_placed_by_health_when_tiles_run_out() {
+        let mut room = started_room(4, 1);
+        room.health = vec![30, 50, 0, 30];
+        room.eliminations = vec![2];
+
+        let places: Vec<usize> = (0..4).map(|index| room.place(index)).collect();
+        assert_eq!(places, vec![2, 1, 4, 2]);
+    }
+}
@@ -1,58 +1,522 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
-use actix::{Actor, AsyncContext, Context, Handler};
+use actix::{Actor, Addr, AsyncContext, Context, Handler, MessageResult};
+use actix_web::web::Data;
+use rand::Rng;
 
-use crate::model::room_manager_messages::{CreateWord, Join, RoomDamagePlayer, RoomNextTurn, RoomNextTurnTimeoutInit, RoomPlayerDead};
+use crate::model::achievement::AchievementId;
+use crate::model::bot_difficulty::BotDifficulty;
+use crate::model::language::Language;
+use crate::model::player_session_messages::{AchievementUnlocked, ActiveRooms, InvalidTarget, LettersSilenced, ReadyCheckFailed, RoomCreated, RoomNotFound};
+use crate::model::room_rules::RoomRules;
+use crate::model::room_manager_messages::{CreatePrivateRoom, CreateTournamentRoom, CreateWord, Join, JoinRanked, JoinRoom, Leave, ListRooms, Practice, RoomForfeitPlayer, RoomChat, RoomCountdownFinished, RoomEmote, RoomNextTurn, RoomNextTurnTimeoutInit, RoomReady, RoomReadyCheckExpired, RoomRollDice, RoomWordCheckFailed, RoomWordValidated, Spectate, StartPrivateRoom, StopSpectating};
 use crate::model::user::User;
-use crate::util::constants::{MAX_PLAYERS_PER_ROOM, TURN_SECONDS};
-use crate::ws::room::Room;
+use crate::repository::mongo_db_achievement_repository::MongoDBAchievementRepository;
+use crate::repository::mongo_db_match_repository::MongoDBMatchRepository;
+use crate::repository::mongo_db_player_stats_repository::MongoDBPlayerStatsRepository;
+use crate::repository::mongo_db_user_repository::MongoDBUserRepository;
+use crate::service::achievement_service::AchievementService;
+use crate::service::env_service::EnvService;
+use crate::service::match_service::MatchService;
+use crate::service::player_stats_service::PlayerStatsService;
+use crate::service::user_service::UserService;
+use crate::util::constants::{BOT_FILL_SECONDS, DISCONNECT_GRACE_SECONDS, LOBBY_COUNTDOWN_SECONDS, MATCHMAKING_INTERVAL_SECONDS, READY_CHECK_TIMEOUT_SECONDS, ROOM_CODE_LENGTH, TOURNAMENT_JOIN_SECONDS};
+use crate::ws::bot_session::BotSession;
+use crate::ws::matchmaking::{find_ranked_group, QueuedPlayer};
+use crate::ws::player_session::PlayerSession;
+use crate::ws::room::{Room, TournamentMatchRef};
+use crate::ws::session_addr::SessionAddr;
+
+const ROOM_CODE_CHARACTERS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
 
 pub struct RoomManager {
     rooms: Vec<Room>,
+    ranked_queue: Vec<QueuedPlayer>,
+    user_service: Data<UserService<MongoDBUserRepository>>,
+    match_service: Data<MatchService<MongoDBMatchRepository>>,
+    player_stats_service: Data<PlayerStatsService<MongoDBPlayerStatsRepository>>,
+    achievement_service: Data<AchievementService<MongoDBAchievementRepository>>,
+    languages: Vec<Language>,
+    bot_fill_timeout: Duration,
 }
 
 impl RoomManager {
-    pub fn new() -> RoomManager {
+    pub fn new(
+        user_service: Data<UserService<MongoDBUserRepository>>,
+        match_service: Data<MatchService<MongoDBMatchRepository>>,
+        player_stats_service: Data<PlayerStatsService<MongoDBPlayerStatsRepository>>,
+        achievement_service: Data<AchievementService<MongoDBAchievementRepository>>,
+        languages: Vec<Language>,
+    ) -> RoomManager {
+        let env_service = EnvService::new();
+        let bot_fill_seconds = env_service.env_data.get("bot_fill_seconds")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(BOT_FILL_SECONDS);
+
         RoomManager {
-            rooms: vec![Room::new(MAX_PLAYERS_PER_ROOM)],
+            rooms: Vec::new(),
+            ranked_queue: Vec::new(),
+            user_service,
+            match_service,
+            player_stats_service,
+            achievement_service,
+            languages,
+            bot_fill_timeout: Duration::from_secs(bot_fill_seconds),
+        }
+    }
+
+    fn supported_language(&self, language: Language) -> Language {
+        if self.languages.contains(&language) {
+            language
+        } else {
+            Language::default()
         }
     }
 
     fn find_room(&mut self, user: &User) -> Option<&mut Room> {
-        self.rooms.iter_mut().find(|room| room.users.contains(user))
+        self.rooms.iter_mut().find(|room| !room.is_finished() && room.contains_user(user))
+    }
+
+    fn find_room_by_code(&mut self, code: &str) -> Option<&mut Room> {
+        self.rooms.iter_mut().find(|room| room.code.as_deref() == Some(code))
+    }
+
+    fn reconnect(&mut self, user: &User, session_addr: Addr<PlayerSession>, ctx: &mut Context<Self>) -> bool {
+        self.ranked_queue.retain(|player| player.user.id != user.id);
+        let joined_room = self.rooms.iter_mut()
+            .find(|room| !room.is_finished() && room.contains_user(user));
+        match joined_room {
+            Some(room) => {
+                if let Some(handle) = room.reconnect_player(user, session_addr) {
+                    ctx.cancel_future(handle);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn generate_room_code(&self) -> String {
+        let mut rng = rand::thread_rng();
+        loop {
+            let code: String = (0..ROOM_CODE_LENGTH)
+                .map(|_| ROOM_CODE_CHARACTERS[rng.gen_range(0..ROOM_CODE_CHARACTERS.len())] as char)
+                .collect();
+            if !self.rooms.iter().any(|room| room.code.as_ref() == Some(&code)) {
+                return code;
+            }
+        }
+    }
+
+    fn match_ranked_players(&mut self, ctx: &mut Context<Self>) {
+        let rules = RoomRules::default();
+        while let Some(mut group) = find_ranked_group(&self.ranked_queue, rules.max_players) {
+            group.sort_unstable_by(|a, b| b.cmp(a));
+            let mut new_room = Room::new_ranked(rules.clone());
+            for index in group {
+                let player = self.ranked_queue.remove(index);
+                new_room.add_player(player.user, player.session_addr.into());
+            }
+            let room_id = new_room.id.clone();
+            self.rooms.push(new_room);
+            self.update_lobby(&room_id, ctx);
+        }
+    }
+
+    fn fill_rooms_with_bots(&mut self, ctx: &mut Context<Self>) {
+        let bot_fill_timeout = self.bot_fill_timeout;
+        let room_ids: Vec<String> = self.rooms.iter()
+            .filter(|room| room.should_fill_with_bots(bot_fill_timeout))
+            .map(|room| room.id.clone())
+            .collect();
+
+        for room_id in room_ids {
+            self.add_bots(&room_id, BotDifficulty::Medium, ctx);
+            self.update_lobby(&room_id, ctx);
+        }
+    }
+
+    fn add_bots(&mut self, room_id: &str, difficulty: BotDifficulty, ctx: &mut Context<Self>) {
+        if let Some(room) = self.rooms.iter_mut().find(|room| room.id == room_id) {
+            for _ in 0..room.free_seats() {
+                let bot = BotSession::new(difficulty, ctx.address(), room.bot_seed());
+                let user = bot.player.clone();
+                room.add_bot(user, bot.start());
+            }
+        }
+    }
+
+    fn update_lobby(&mut self, room_id: &str, ctx: &mut Context<Self>) {
+        let room = match self.rooms.iter_mut().find(|room| room.id == room_id) {
+            Some(room) => room,
+            None => return,
+        };
+        if room.has_started() {
+            return;
+        }
+
+        if room.is_awaiting_ready_check() {
+            if room.ready_check_timeout.is_none() {
+                let room_id = room.id.clone();
+                let ready_check_timeout_future = move |_manager: &mut RoomManager, ctx: &mut Context<Self>| {
+                    ctx.address().do_send(RoomReadyCheckExpired {
+                        room_id
+                    });
+                };
+                room.ready_check_timeout = Some(
+                    ctx.run_later(Duration::from_secs(READY_CHECK_TIMEOUT_SECONDS), ready_check_timeout_future)
+                );
+            }
+        } else if let Some(handle) = room.ready_check_timeout.take() {
+            ctx.cancel_future(handle);
+        }
+
+        if room.is_awaiting_ready_check() && room.is_everyone_ready() {
+            if room.lobby_countdown.is_none() {
+                let room_id = room.id.clone();
+                let countdown_future = move |_manager: &mut RoomManager, ctx: &mut Context<Self>| {
+                    ctx.address().do_send(RoomCountdownFinished {
+                        room_id
+                    });
+                };
+                room.start_countdown(
+                    ctx.run_later(Duration::from_secs(LOBBY_COUNTDOWN_SECONDS), countdown_future)
+                );
+            }
+        } else if let Some(handle) = room.take_countdown() {
+            ctx.cancel_future(handle);
+        }
+
+        room.broadcast_lobby_state();
+    }
+
+    fn save_ratings(&self, room: &Room) {
+        let result = match room.result() {
+            Some(result) if room.is_ranked() => result,
+            _ => return,
+        };
+        let users: Vec<User> = result.players.iter()
+            .filter_map(|player| {
+                let rating = player.rating?;
+                Some(User { rating, ..player.user.clone() })
+            })
+            .collect();
+
+        let user_service = self.user_service.clone();
+        actix::spawn(async move {
+            for user in users {
+                user_service.update_user(user).await;
+            }
+        });
+    }
+
+    fn save_match(&self, room: &Room) {
+        let match_record = room.match_record();
+        let match_service = self.match_service.clone();
+        let player_stats_service = self.player_stats_service.clone();
+        actix::spawn(async move {
+            player_stats_service.record_match(&match_record).await;
+            match_service.save_match(match_record).await;
+        });
+    }
+
+    fn schedule_tournament_join_deadline(&self, room_id: String, ctx: &mut Context<Self>) {
+        ctx.run_later(Duration::from_secs(TOURNAMENT_JOIN_SECONDS), move |manager, ctx| {
+            manager.check_tournament_join_deadline(room_id, ctx);
+        });
+    }
+
+    fn check_tournament_join_deadline(&mut self, room_id: String, ctx: &mut Context<Self>) {
+        let room = match self.rooms.iter_mut().find(|room| room.id == room_id) {
+            Some(room) => room,
+            None => return,
+        };
+        if room.has_started() {
+            return;
+        }
+        if room.is_full() {
+            self.schedule_tournament_join_deadline(room_id, ctx);
+            return;
+        }
+
+        if let Some(handle) = room.take_countdown() {
+            ctx.cancel_future(handle);
+        }
+        if let Some(handle) = room.ready_check_timeout.take() {
+            ctx.cancel_future(handle);
+        }
+        for handle in room.take_disconnect_timeouts() {
+            ctx.cancel_future(handle);
+        }
+        room.award_walkover();
+        self.rooms.retain(|room| room.id != room_id);
+    }
+
+    fn save_achievements(&mut self) {
+        let mut unlocks: HashMap<String, (Addr<PlayerSession>, Vec<AchievementId>)> = HashMap::new();
+        for room in self.rooms.iter_mut() {
+            for (user, player_session_addr, achievement_id) in room.take_unlocked_achievements() {
+                unlocks.entry(user.id)
+                    .or_insert_with(|| (player_session_addr, Vec::new()))
+                    .1.push(achievement_id);
+            }
+        }
+        for (user_id, (player_session_addr, achievement_ids)) in unlocks {
+            let achievement_service = self.achievement_service.clone();
+            actix::spawn(async move {
+                for badge in achievement_service.unlock(&user_id, &achievement_ids).await {
+                    player_session_addr.do_send(AchievementUnlocked { badge });
+                }
+            });
+        }
+    }
+
+    fn remove_closed_rooms(&mut self, ctx: &mut Context<Self>) {
+        self.save_achievements();
+        for room in self.rooms.iter_mut().filter(|room| room.is_finished() || room.is_abandoned()) {
+            if let Some(handle) = room.next_turn_timeout.take() {
+                ctx.cancel_future(handle);
+            }
+            for handle in room.take_disconnect_timeouts() {
+                ctx.cancel_future(handle);
+            }
+            if let Some(handle) = room.take_countdown() {
+                ctx.cancel_future(handle);
+            }
+            if let Some(handle) = room.ready_check_timeout.take() {
+                ctx.cancel_future(handle);
+            }
+            if !room.is_finished() {
+                room.close_bots();
+            }
+        }
+        for room in self.rooms.iter().filter(|room| room.is_finished()) {
+            self.save_ratings(room);
+            self.save_match(room);
+            room.report_tournament_result();
+        }
+        self.rooms.retain(|room| !room.is_finished() && !room.is_abandoned());
     }
 }
 
 impl Actor for RoomManager {
     type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        ctx.run_interval(Duration::from_secs(MATCHMAKING_INTERVAL_SECONDS), |manager, ctx| {
+            manager.match_ranked_players(ctx);
+            manager.fill_rooms_with_bots(ctx);
+        });
+    }
 }
 
 impl Handler<Join> for RoomManager {
     type Result = ();
 
-    fn handle(&mut self, msg: Join, _ctx: &mut Context<Self>) {
-        if let Some(room) = self.rooms.last_mut() {
-            if room.is_full() {
-                let mut new_room = Room::new(MAX_PLAYERS_PER_ROOM);
-                new_room.add_player(msg.user, msg.session_addr);
+    fn handle(&mut self, msg: Join, ctx: &mut Context<Self>) {
+        if self.reconnect(&msg.user, msg.session_addr.clone(), ctx) {
+            return;
+        }
+
+        let language = self.supported_language(msg.language);
+        let open_room = self.rooms.iter_mut()
+            .find(|room| room.is_open_for_matchmaking() && room.rules().language == language);
+        let room_id = match open_room {
+            Some(room) => {
+                room.add_player(msg.user, msg.session_addr.into());
+                room.id.clone()
+            }
+            None => {
+                let rules = RoomRules {
+                    language,
+                    ..RoomRules::default()
+                };
+                let mut new_room = Room::new(rules);
+                new_room.add_player(msg.user, msg.session_addr.into());
+                let room_id = new_room.id.clone();
                 self.rooms.push(new_room);
-            } else {
-                room.add_player(msg.user, msg.session_addr);
-                if room.is_full() {
-                    room.start_game();
-                }
+                room_id
+            }
+        };
+        self.update_lobby(&room_id, ctx);
+    }
+}
+
+impl Handler<JoinRanked> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: JoinRanked, ctx: &mut Context<Self>) {
+        if self.reconnect(&msg.user, msg.session_addr.clone(), ctx) {
+            return;
+        }
+
+        self.ranked_queue.push(QueuedPlayer::new(msg.user, msg.session_addr));
+        self.match_ranked_players(ctx);
+    }
+}
+
+impl Handler<Practice> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: Practice, ctx: &mut Context<Self>) {
+        if self.reconnect(&msg.user, msg.session_addr.clone(), ctx) {
+            return;
+        }
+
+        let mut new_room = Room::new_practice(RoomRules::default());
+        new_room.add_player(msg.user.clone(), msg.session_addr.into());
+        new_room.set_ready(&msg.user, true);
+        let room_id = new_room.id.clone();
+        self.rooms.push(new_room);
+
+        self.add_bots(&room_id, msg.difficulty, ctx);
+        self.update_lobby(&room_id, ctx);
+    }
+}
+
+impl Handler<CreatePrivateRoom> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: CreatePrivateRoom, ctx: &mut Context<Self>) {
+        if self.reconnect(&msg.user, msg.session_addr.clone(), ctx) {
+            return;
+        }
+
+        let code = self.generate_room_code();
+        let mut rules = RoomRules::from_choice(msg.rules);
+        rules.language = self.supported_language(rules.language);
+        let mut new_room = Room::new_private(rules.clone(), code.clone(), &msg.user);
+        new_room.add_player(msg.user, msg.session_addr.clone().into());
+        let room_id = new_room.id.clone();
+        self.rooms.push(new_room);
+
+        msg.session_addr.do_send(RoomCreated {
+            code,
+            rules,
+        });
+        self.update_lobby(&room_id, ctx);
+    }
+}
+
+impl Handler<CreateTournamentRoom> for RoomManager {
+    type Result = MessageResult<CreateTournamentRoom>;
+
+    fn handle(&mut self, msg: CreateTournamentRoom, ctx: &mut Context<Self>) -> Self::Result {
+        let code = self.generate_room_code();
+        let tournament_match = TournamentMatchRef {
+            tournament_id: msg.tournament_id,
+            match_id: msg.match_id,
+            user_ids: msg.users.iter().map(|user| user.id.clone()).collect(),
+            result_recipient: msg.result_recipient,
+        };
+        let mut rules = msg.rules;
+        rules.language = self.supported_language(rules.language);
+        let room = Room::new_tournament(rules, code.clone(), tournament_match);
+        self.schedule_tournament_join_deadline(room.id.clone(), ctx);
+        self.rooms.push(room);
+        MessageResult(code)
+    }
+}
+
+impl Handler<JoinRoom> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: JoinRoom, ctx: &mut Context<Self>) {
+        if self.reconnect(&msg.user, msg.session_addr.clone(), ctx) {
+            return;
+        }
+
+        let code = msg.code.trim().to_ascii_uppercase();
+        match self.find_room_by_code(&code) {
+            Some(room) if room.can_join(&msg.user) => {
+                room.add_player(msg.user, msg.session_addr.into());
+                let room_id = room.id.clone();
+                self.update_lobby(&room_id, ctx);
+            }
+            _ => {
+                msg.session_addr.do_send(RoomNotFound {
+                    code
+                });
+            }
+        }
+    }
+}
+
+impl Handler<StartPrivateRoom> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: StartPrivateRoom, ctx: &mut Context<Self>) {
+        if let Some(room) = self.find_room(&msg.user) {
+            if room.can_start_early(&msg.user) {
+                room.request_start();
+                let room_id = room.id.clone();
+                self.update_lobby(&room_id, ctx);
             }
         }
     }
 }
 
+impl Handler<Spectate> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: Spectate, _ctx: &mut Context<Self>) {
+        let room = self.rooms.iter_mut()
+            .find(|room| room.id == msg.room_id && room.has_started() && !room.is_finished());
+        match room {
+            Some(room) => room.add_spectator(msg.session_addr),
+            None => msg.session_addr.do_send(RoomNotFound {
+                code: msg.room_id
+            }),
+        }
+    }
+}
+
+impl Handler<StopSpectating> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: StopSpectating, _ctx: &mut Context<Self>) {
+        for room in self.rooms.iter_mut() {
+            room.remove_spectator(&msg.session_addr);
+        }
+    }
+}
+
+impl Handler<ListRooms> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: ListRooms, _ctx: &mut Context<Self>) {
+        let rooms = self.rooms.iter()
+            .filter(|room| room.has_started() && !room.is_finished() && !room.is_private())
+            .map(|room| room.summary())
+            .collect();
+        msg.session_addr.do_send(ActiveRooms {
+            rooms
+        });
+    }
+}
+
 impl Handler<CreateWord> for RoomManager {
     type Result = ();
 
     fn handle(&mut self, msg: CreateWord, ctx: &mut Self::Context) {
         if let Some(room) = self.find_room(&msg.user) {
             let is_player_turn = room.is_player_turn(msg.session_addr.clone());
-            if !is_player_turn {
+            if !is_player_turn || room.is_waiting_for_word() {
+                return;
+            }
+            if let Some(target_user_id) = msg.target_user_id.clone() {
+                if !room.is_valid_target(&msg.session_addr, &target_user_id) {
+                    msg.session_addr.do_send(InvalidTarget { target_user_id });
+                    return;
+                }
+            }
+            let silenced_letters = room.silenced_letters(&msg.session_addr, &msg.word);
+            if !silenced_letters.is_empty() {
+                msg.session_addr.do_send(LettersSilenced {
+                    word: msg.word,
+                    letters: silenced_letters,
+                });
                 return;
             }
 
@@ -60,7 +524,7 @@ impl Handler<CreateWord> for RoomManager {
                 ctx.cancel_future(handle);
                 room.next_turn_timeout = None;
             }
-            room.on_word_created(msg.word, msg.session_addr);
+            room.on_word_created(msg.word, msg.session_addr, msg.target_user_id);
         }
     }
 }
@@ -68,10 +532,11 @@ impl Handler<CreateWord> for RoomManager {
 impl Handler<RoomNextTurn> for RoomManager {
     type Result = ();
 
-    fn handle(&mut self, msg: RoomNextTurn, _ctx: &mut Self::Context) {
+    fn handle(&mut self, msg: RoomNextTurn, ctx: &mut Self::Context) {
         if let Some(room) = self.find_room(&msg.user) {
             room.increase_turn_index();
         }
+        self.remove_closed_rooms(ctx);
     }
 }
 
@@ -80,37 +545,191 @@ impl Handler<RoomNextTurnTimeoutInit> for RoomManager {
 
     fn handle(&mut self, msg: RoomNextTurnTimeoutInit, ctx: &mut Self::Context) {
         if let Some(room) = self.find_room(&msg.user) {
-            if let Some(_) = room.next_turn_timeout {
+            if room.next_turn_timeout.is_some() {
                 return;
             }
+            // A turn resumed after a failed word check keeps only the time it had left.
+            let remaining_turn_time = room.remaining_turn_time();
             let roll_dice_timeout_future = move |_session: &mut RoomManager, ctx: &mut Self::Context| {
                 ctx.address().do_send(RoomNextTurn {
                     user: msg.user.clone()
                 });
             };
             room.next_turn_timeout = Some(
-                ctx.run_later(Duration::from_secs(TURN_SECONDS), roll_dice_timeout_future)
+                ctx.run_later(remaining_turn_time, roll_dice_timeout_future)
             );
         }
     }
 }
 
-impl Handler<RoomDamagePlayer> for RoomManager {
+impl Handler<RoomRollDice> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomRollDice, ctx: &mut Self::Context) {
+        if let Some(room) = self.find_room(&msg.user) {
+            room.roll_dice(&msg.user);
+        }
+        self.remove_closed_rooms(ctx);
+    }
+}
+
+impl Handler<RoomWordValidated> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomWordValidated, _ctx: &mut Self::Context) {
+        if let Some(room) = self.find_room(&msg.user) {
+            room.on_word_validated(&msg.user, msg.word, msg.target_index);
+        }
+        self.save_achievements();
+    }
+}
+
+impl Handler<RoomWordCheckFailed> for RoomManager {
     type Result = ();
 
-    fn handle(&mut self, msg: RoomDamagePlayer, _ctx: &mut Self::Context) {
+    fn handle(&mut self, msg: RoomWordCheckFailed, _ctx: &mut Self::Context) {
         if let Some(room) = self.find_room(&msg.user) {
-            room.on_damage_player(msg.damage, msg.player_index);
+            room.on_word_check_failed(&msg.user);
         }
     }
 }
 
-impl Handler<RoomPlayerDead> for RoomManager {
+impl Handler<Leave> for RoomManager {
     type Result = ();
 
-    fn handle(&mut self, msg: RoomPlayerDead, _ctx: &mut Self::Context) {
+    fn handle(&mut self, msg: Leave, ctx: &mut Self::Context) {
+        self.ranked_queue.retain(|player| player.session_addr != msg.session_addr);
+        for room in self.rooms.iter_mut() {
+            room.remove_spectator(&msg.session_addr);
+        }
+
         if let Some(room) = self.find_room(&msg.user) {
-            room.on_player_dead(msg);
+            let session_addr = SessionAddr::from(msg.session_addr);
+            if !room.sessions.contains(&session_addr) {
+                return;
+            }
+
+            if room.has_started() {
+                let user = msg.user.clone();
+                let forfeit_timeout_future = move |_manager: &mut RoomManager, ctx: &mut Self::Context| {
+                    ctx.address().do_send(RoomForfeitPlayer {
+                        user
+                    });
+                };
+                let forfeit_timeout = ctx.run_later(Duration::from_secs(DISCONNECT_GRACE_SECONDS), forfeit_timeout_future);
+                room.disconnect_player(&msg.user, forfeit_timeout);
+
+                if room.is_player_turn(session_addr) {
+                    if let Some(handle) = room.next_turn_timeout.take() {
+                        ctx.cancel_future(handle);
+                    }
+                    room.increase_turn_index();
+                }
+            } else if room.is_ranked() {
+                room.remove_player(&msg.user);
+                for (user, session_addr) in room.take_players() {
+                    if let SessionAddr::Player(session_addr) = session_addr {
+                        self.ranked_queue.push(QueuedPlayer::new(user, session_addr));
+                    }
+                }
+            } else {
+                room.remove_player(&msg.user);
+                let room_id = room.id.clone();
+                self.update_lobby(&room_id, ctx);
+            }
+        }
+        self.remove_closed_rooms(ctx);
+    }
+}
+
+impl Handler<RoomForfeitPlayer> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomForfeitPlayer, ctx: &mut Self::Context) {
+        if let Some(room) = self.find_room(&msg.user) {
+            room.forfeit_player(&msg.user);
+        }
+        self.remove_closed_rooms(ctx);
+    }
+}
+
+impl Handler<RoomChat> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomChat, _ctx: &mut Self::Context) {
+        if let Some(room) = self.find_room(&msg.user) {
+            room.on_chat(&msg.user, msg.message);
+        }
+    }
+}
+
+impl Handler<RoomEmote> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomEmote, _ctx: &mut Self::Context) {
+        if let Some(room) = self.find_room(&msg.user) {
+            room.on_emote(&msg.user, msg.emote);
+        }
+    }
+}
+
+impl Handler<RoomReady> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomReady, ctx: &mut Self::Context) {
+        if let Some(room) = self.find_room(&msg.user) {
+            if room.has_started() {
+                return;
+            }
+            room.set_ready(&msg.user, msg.ready);
+            let room_id = room.id.clone();
+            self.update_lobby(&room_id, ctx);
+        }
+    }
+}
+
+impl Handler<RoomCountdownFinished> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomCountdownFinished, ctx: &mut Self::Context) {
+        if let Some(room) = self.rooms.iter_mut().find(|room| room.id == msg.room_id) {
+            room.take_countdown();
+            if !room.is_awaiting_ready_check() || !room.is_everyone_ready() {
+                return;
+            }
+            if let Some(handle) = room.ready_check_timeout.take() {
+                ctx.cancel_future(handle);
+            }
+            room.start_game();
+        }
+    }
+}
+
+impl Handler<RoomReadyCheckExpired> for RoomManager {
+    type Result = ();
+
+    fn handle(&mut self, msg: RoomReadyCheckExpired, ctx: &mut Self::Context) {
+        let room = match self.rooms.iter_mut().find(|room| room.id == msg.room_id) {
+            Some(room) => room,
+            None => return,
+        };
+        room.ready_check_timeout = None;
+        if room.has_started() {
+            return;
+        }
+
+        for session_addr in room.remove_unready_players() {
+            session_addr.do_send(ReadyCheckFailed);
+        }
+        if room.is_ranked() {
+            for (user, session_addr) in room.take_players() {
+                if let SessionAddr::Player(session_addr) = session_addr {
+                    self.ranked_queue.push(QueuedPlayer::new(user, session_addr));
+                }
+            }
+        } else {
+            self.update_lobby(&msg.room_id, ctx);
         }
+        self.remove_closed_rooms(ctx);
     }
 }
\ No newline at end of file
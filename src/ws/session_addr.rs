@@ -0,0 +1,44 @@
+use actix::{Actor, Addr, Handler, Message};
+use actix::dev::ToEnvelope;
+
+use crate::ws::bot_session::BotSession;
+use crate::ws::player_session::PlayerSession;
+
+#[derive(PartialEq, Clone)]
+pub enum SessionAddr {
+    Player(Addr<PlayerSession>),
+    Bot(Addr<BotSession>),
+}
+
+impl SessionAddr {
+    pub fn do_send<M>(&self, msg: M)
+        where
+            M: Message + Send + 'static,
+            M::Result: Send,
+            PlayerSession: Handler<M>,
+            BotSession: Handler<M>,
+            <PlayerSession as Actor>::Context: ToEnvelope<PlayerSession, M>,
+            <BotSession as Actor>::Context: ToEnvelope<BotSession, M>,
+    {
+        match self {
+            SessionAddr::Player(addr) => addr.do_send(msg),
+            SessionAddr::Bot(addr) => addr.do_send(msg),
+        }
+    }
+
+    pub fn is_player(&self) -> bool {
+        matches!(self, SessionAddr::Player(_))
+    }
+}
+
+impl From<Addr<PlayerSession>> for SessionAddr {
+    fn from(addr: Addr<PlayerSession>) -> Self {
+        SessionAddr::Player(addr)
+    }
+}
+
+impl From<Addr<BotSession>> for SessionAddr {
+    fn from(addr: Addr<BotSession>) -> Self {
+        SessionAddr::Bot(addr)
+    }
+}
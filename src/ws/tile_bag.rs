@@ -0,0 +1,167 @@
+use rand::Rng;
+
+use crate::language::language_registry::get_language_definition;
+use crate::model::letter::Letter;
+use crate::model::room_rules::RoomRules;
+
+pub struct TileBag {
+    tiles: Vec<Letter>,
+    vowels: &'static [char],
+}
+
+impl TileBag {
+    pub fn new(rules: &RoomRules) -> TileBag {
+        let language_definition = get_language_definition(rules.language);
+        let tiles = language_definition.letters.iter()
+            .flat_map(|letter_definition| {
+                let count = rules.tile_counts.as_ref()
+                    .and_then(|tile_counts| tile_counts.get(&letter_definition.letter).copied())
+                    .unwrap_or(letter_definition.frequency);
+                (0..count).map(move |_| letter_definition.to_letter())
+            })
+            .collect();
+
+        TileBag {
+            tiles,
+            vowels: language_definition.vowels,
+        }
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn fill_rack(&mut self, rng: &mut impl Rng, mut rack: Vec<Letter>, rules: &RoomRules) -> Vec<Letter> {
+        let rack_size = rules.rack_size;
+        let first_drawn_index = rack.len();
+        let missing_count = rack_size.saturating_sub(rack.len());
+        let rack_vowels_count = rack.iter().filter(|letter| self.is_vowel(letter)).count();
+        let vowels_needed = rules.min_vowels.unwrap_or(0)
+            .saturating_sub(rack_vowels_count)
+            .min(missing_count);
+
+        for _ in 0..vowels_needed {
+            match self.draw_vowel(rng) {
+                Some(letter) => rack.push(letter),
+                None => break,
+            }
+        }
+        while rack.len() < rack_size {
+            match self.draw(rng) {
+                Some(letter) => rack.push(letter),
+                None => break,
+            }
+        }
+        for letter in rack.iter_mut().skip(first_drawn_index) {
+            letter.modifier = rules.tile_modifier_rates.pick(rng.gen());
+        }
+        rack
+    }
+
+    pub fn return_tiles(&mut self, letters: Vec<Letter>) {
+        self.tiles.extend(letters.into_iter().map(|letter| Letter { modifier: None, ..letter }));
+    }
+
+    fn draw(&mut self, rng: &mut impl Rng) -> Option<Letter> {
+        if self.tiles.is_empty() {
+            return None;
+        }
+        let index = rng.gen_range(0..self.tiles.len());
+        Some(self.tiles.swap_remove(index))
+    }
+
+    fn draw_vowel(&mut self, rng: &mut impl Rng) -> Option<Letter> {
+        let vowel_indices: Vec<usize> = (0..self.tiles.len())
+            .filter(|&index| self.is_vowel(&self.tiles[index]))
+            .collect();
+        if vowel_indices.is_empty() {
+            return None;
+        }
+        let index = vowel_indices[rng.gen_range(0..vowel_indices.len())];
+        Some(self.tiles.swap_remove(index))
+    }
+
+    fn is_vowel(&self, letter: &Letter) -> bool {
+        self.vowels.contains(&letter.letter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::language::language_registry::{get_languages, LanguageDefinition};
+
+    fn rules_for(language_definition: &LanguageDefinition) -> RoomRules {
+        RoomRules {
+            language: language_definition.language,
+            ..RoomRules::default()
+        }
+    }
+
+    #[test]
+    fn bags_hold_each_letter_as_often_as_the_language_defines() {
+        for language_definition in get_languages() {
+            let tile_bag = TileBag::new(&rules_for(language_definition));
+            for letter_definition in language_definition.letters {
+                let count = tile_bag.tiles.iter()
+                    .filter(|tile| tile.letter == letter_definition.letter)
+                    .count();
+                assert_eq!(count, letter_definition.frequency as usize, "{}", letter_definition.letter);
+            }
+            let total: u32 = language_definition.letters.iter().map(|letter_definition| letter_definition.frequency).sum();
+            assert_eq!(tile_bag.remaining(), total as usize);
+        }
+    }
+
+    #[test]
+    fn tile_counts_override_the_language_frequencies() {
+        let language_definition = &get_languages()[0];
+        let rules = RoomRules {
+            tile_counts: Some(HashMap::from([('A', 1), ('E', 0)])),
+            ..rules_for(language_definition)
+        };
+        let tile_bag = TileBag::new(&rules);
+        assert_eq!(tile_bag.tiles.iter().filter(|tile| tile.letter == 'A').count(), 1);
+        assert!(tile_bag.tiles.iter().all(|tile| tile.letter != 'E'));
+    }
+
+    #[test]
+    fn racks_get_at_least_the_minimum_vowels() {
+        for language_definition in get_languages() {
+            let rules = RoomRules {
+                min_vowels: Some(3),
+                ..rules_for(language_definition)
+            };
+            for seed in 0..50 {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                let mut tile_bag = TileBag::new(&rules);
+                let rack = tile_bag.fill_rack(&mut rng, Vec::new(), &rules);
+                let vowels = rack.iter().filter(|letter| tile_bag.is_vowel(letter)).count();
+                assert_eq!(rack.len(), rules.rack_size);
+                assert!(vowels >= 3, "seed {} drew {} vowels", seed, vowels);
+            }
+        }
+    }
+
+    #[test]
+    fn draining_the_bag_leaves_short_racks() {
+        let rules = RoomRules::default();
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        let mut tile_bag = TileBag::new(&rules);
+        let total = tile_bag.remaining();
+        let mut drawn = 0;
+        while tile_bag.remaining() > 0 {
+            drawn += tile_bag.fill_rack(&mut rng, Vec::new(), &rules).len();
+        }
+        assert_eq!(drawn, total);
+        assert!(tile_bag.fill_rack(&mut rng, Vec::new(), &rules).is_empty());
+
+        tile_bag.return_tiles(vec![get_languages()[0].letters[0].to_letter()]);
+        assert_eq!(tile_bag.fill_rack(&mut rng, Vec::new(), &rules).len(), 1);
+    }
+}
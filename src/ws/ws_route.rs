@@ -2,41 +2,43 @@ use actix::Addr;
 use actix_web::{HttpRequest, HttpResponse, web};
 use actix_web_actors::ws;
 
-use crate::model::user::User;
+use crate::authorization::request_user::get_request_user;
+use crate::repository::mongo_db_match_repository::MongoDBMatchRepository;
+use crate::repository::mongo_db_user_repository::MongoDBUserRepository;
+use crate::service::dictionary_service::DictionaryService;
 use crate::service::facebook_service::FacebookService;
+use crate::service::match_service::MatchService;
+use crate::service::user_service::UserService;
+use crate::tournament::tournament_manager::TournamentManager;
 use crate::ws::player_session::PlayerSession;
 use crate::ws::room_manager::RoomManager;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn ws_route(
     req: HttpRequest,
     stream: web::Payload,
     room_manager: web::Data<Addr<RoomManager>>,
+    tournament_manager: web::Data<Addr<TournamentManager>>,
     facebook_service: web::Data<FacebookService>,
+    user_service: web::Data<UserService<MongoDBUserRepository>>,
+    dictionary_service: web::Data<DictionaryService>,
+    match_service: web::Data<MatchService<MongoDBMatchRepository>>,
 ) -> HttpResponse {
-    let authorization_header = get_authorization_header(&req);
-    let authorization_header = match authorization_header {
-        Some(header) => header,
+    let player = match get_request_user(&req, &facebook_service, &user_service).await {
+        Some(player) => player,
         None => { return HttpResponse::Unauthorized().finish(); }
     };
-    let replaced = authorization_header.replace("Bearer ", "");
-    let authorization_bearer = replaced.as_str();
 
-    let facebook_profile = facebook_service.get_facebook_profile(authorization_bearer).await;
-    let facebook_profile = match facebook_profile {
-        Ok(profile) => profile,
-        Err(_) => { return HttpResponse::Unauthorized().finish(); }
-    };
-
-    let player = User::from_facebook_profile(facebook_profile);
-    let session = PlayerSession::new(player, room_manager.get_ref().clone());
+    let session = PlayerSession::new(
+        player,
+        room_manager.get_ref().clone(),
+        tournament_manager.get_ref().clone(),
+        dictionary_service,
+        match_service,
+    );
     let response = ws::start(session, &req, stream);
-    let response = match response {
+    match response {
         Ok(res) => res,
-        Err(_) => { return HttpResponse::Unauthorized().finish(); }
-    };
-    response
+        Err(_) => HttpResponse::Unauthorized().finish(),
+    }
 }
-
-fn get_authorization_header(req: &HttpRequest) -> Option<&str> {
-    req.headers().get("Authorization")?.to_str().ok()
-}
\ No newline at end of file